@@ -0,0 +1,90 @@
+//! Host baseline of the NTT: pqc_kyber against the pqcrystals C reference
+//! (with `c-reference`) and the translations in `transpilation/`, on the same
+//! polynomials.
+//!
+//! `cargo bench --target x86_64-unknown-linux-gnu --no-default-features --features std,c-reference --bench ntt`
+//!
+//! Every iteration copies the input polynomial before transforming it in
+//! place, for all implementations alike. The C translations also go through
+//! the padded scratch buffer of `transpiled`, which is part of what they cost.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::polynomial;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+#[cfg(feature = "c-reference")]
+use kyber_rust::{c_ref, ntt_asm::ZETAS};
+use kyber_rust::ntt_asm::ntt_from_asm;
+use kyber_rust::poly::poly_basemul;
+use kyber_rust::transpiled::{ntt_asm_to_c_alt, ntt_rust_to_c};
+use pqc_kyber::{invntt, ntt};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 0x4e54_5400;
+
+/// Runs `f` on a copy of `input`.
+fn transform(input: &[i16; 256], f: impl Fn(&mut [i16; 256])) -> [i16; 256] {
+    let mut r = *black_box(input);
+    f(&mut r);
+    r
+}
+
+fn bench_ntt(c: &mut Criterion) {
+    let a = polynomial(&mut SmallRng::seed_from_u64(SEED));
+    let mut group = c.benchmark_group("ntt");
+    group.bench_function("pqc_kyber", |b| b.iter(|| transform(&a, |r| ntt(r))));
+    #[cfg(feature = "c-reference")]
+    group.bench_function("c_reference", |b| b.iter(|| transform(&a, c_ref::ntt)));
+    group.bench_function("ntt_from_asm", |b| b.iter(|| transform(&a, ntt_from_asm)));
+    group.bench_function("rust_ntt_to_c", |b| b.iter(|| transform(&a, ntt_rust_to_c)));
+    group.bench_function("ntt_asm_to_c_alt", |b| b.iter(|| transform(&a, ntt_asm_to_c_alt)));
+    group.finish();
+}
+
+fn bench_invntt(c: &mut Criterion) {
+    let a = polynomial(&mut SmallRng::seed_from_u64(SEED));
+    let mut group = c.benchmark_group("invntt");
+    group.bench_function("pqc_kyber", |b| b.iter(|| transform(&a, |r| invntt(r))));
+    #[cfg(feature = "c-reference")]
+    group.bench_function("c_reference", |b| b.iter(|| transform(&a, c_ref::invntt)));
+    group.finish();
+}
+
+/// `poly::poly_basemul` against the C reference: 64 pairs of degree-one
+/// products.
+fn bench_basemul(c: &mut Criterion) {
+    let mut rng = SmallRng::seed_from_u64(SEED);
+    let a = polynomial(&mut rng);
+    let b = polynomial(&mut rng);
+    let mut group = c.benchmark_group("basemul");
+    group.bench_function("pqc_kyber", |bench| {
+        bench.iter(|| {
+            let (a, b) = black_box((&a, &b));
+            let mut r = [0i16; 256];
+            poly_basemul(&mut r, a, b);
+            r
+        })
+    });
+    #[cfg(feature = "c-reference")]
+    group.bench_function("c_reference", |bench| {
+        bench.iter(|| {
+            let (a, b) = black_box((&a, &b));
+            let mut r = [0i16; 256];
+            for i in 0..64 {
+                let zeta = ZETAS[64 + i];
+                for (j, zeta) in [(4 * i, zeta), (4 * i + 2, -zeta)] {
+                    let mut pair = [0i16; 2];
+                    c_ref::basemul(&mut pair, &[a[j], a[j + 1]], &[b[j], b[j + 1]], zeta);
+                    r[j..j + 2].copy_from_slice(&pair);
+                }
+            }
+            r
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ntt, bench_invntt, bench_basemul);
+criterion_main!(benches);
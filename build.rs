@@ -7,19 +7,39 @@
 //! Cargo re-run the build script whenever `memory.x` is changed,
 //! updating `memory.x` ensures a rebuild of the application with the
 //! new memory settings.
+//!
+//! It also turns the NIST Known-Answer-Test files found in `kat/` into the
+//! `kat::VECTORS` table used by the `kat` example, on host targets compiles
+//! the C translations of the NTT for the differential tests and, with the
+//! `c-reference` feature, compiles the pqcrystals C reference.
 
 use std::env;
-use std::fs::File;
+use std::fmt::Write as _;
+use std::fs::{self, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Number of KAT entries embedded in the firmware unless `KAT_VECTORS` says
+/// otherwise; a Kyber1024 entry takes about 6.4 KiB of flash.
+const DEFAULT_KAT_VECTORS: usize = 10;
 
 fn main() {
     // Put `memory.x` in our output directory and ensure it's
-    // on the linker search path.
+    // on the linker search path. The QEMU board profile has its own layout.
+    let memory: &[u8] = if env::var_os("CARGO_FEATURE_QEMU").is_some() {
+        include_bytes!("memory-qemu.x")
+    } else {
+        include_bytes!("memory.x")
+    };
     let out = &PathBuf::from(env::var_os("OUT_DIR").unwrap());
-    File::create(out.join("memory.x"))
+    let mut memory_x = File::create(out.join("memory.x")).unwrap();
+    memory_x.write_all(memory).unwrap();
+    if env::var_os("CARGO_FEATURE_CCRAM_STACK").is_some() {
+        writeln!(memory_x, "\n_stack_start = ORIGIN(CCRAM) + LENGTH(CCRAM);").unwrap();
+    }
+    File::create(out.join("ccram.x"))
         .unwrap()
-        .write_all(include_bytes!("memory.x"))
+        .write_all(include_bytes!("ccram.x"))
         .unwrap();
     println!("cargo:rustc-link-search={}", out.display());
 
@@ -28,4 +48,136 @@ fn main() {
     // here, we ensure the build script is only re-run when
     // `memory.x` is changed.
     println!("cargo:rerun-if-changed=memory.x");
+    println!("cargo:rerun-if-changed=memory-qemu.x");
+    println!("cargo:rerun-if-changed=ccram.x");
+
+    // recorded in the benchmark reports
+    println!("cargo:rustc-env=KYBER_RUST_OPT_LEVEL={}", env::var("OPT_LEVEL").unwrap());
+
+    kat_vectors(out);
+
+    if env::var("CARGO_CFG_TARGET_OS").unwrap() != "none" {
+        transpiled_c();
+    }
+    if env::var_os("CARGO_FEATURE_C_REFERENCE").is_some() {
+        c_reference();
+    }
+}
+
+/// Where the `ref/` directory of github.com/pq-crystals/kyber is looked for
+/// unless `PQCRYSTALS_KYBER_REF` says otherwise.
+const DEFAULT_C_REFERENCE: &str = "pqcrystals-kyber/ref";
+
+/// Builds the pqcrystals C reference for the target (arm-none-eabi-gcc for
+/// thumbv7em, picked by cc) with the `KYBER_K` of the selected parameter set,
+/// exposed by the `c_ref` module. `randombytes` is provided by `c_ref`.
+fn c_reference() {
+    println!("cargo:rerun-if-env-changed=PQCRYSTALS_KYBER_REF");
+    let dir = PathBuf::from(env::var("PQCRYSTALS_KYBER_REF").unwrap_or_else(|_| DEFAULT_C_REFERENCE.into()));
+    if !dir.join("kem.c").exists() {
+        panic!(
+            "the `c-reference` feature needs the pqcrystals Kyber `ref/` sources in {} \
+             (set PQCRYSTALS_KYBER_REF to their location)",
+            dir.display()
+        );
+    }
+
+    let k = if env::var_os("CARGO_FEATURE_KYBER512").is_some() {
+        "2"
+    } else if env::var_os("CARGO_FEATURE_KYBER1024").is_some() {
+        "4"
+    } else {
+        "3"
+    };
+    let sources = [
+        "kem.c",
+        "indcpa.c",
+        "polyvec.c",
+        "poly.c",
+        "ntt.c",
+        "cbd.c",
+        "reduce.c",
+        "verify.c",
+        "fips202.c",
+        "symmetric-shake.c",
+    ];
+    for source in sources.iter() {
+        println!("cargo:rerun-if-changed={}", dir.join(source).display());
+    }
+    // same flags as the README analysis, the opt-level follows the profile
+    cc::Build::new()
+        .files(sources.iter().map(|s| dir.join(s)))
+        .include(&dir)
+        .define("KYBER_K", k)
+        .flag_if_supported("-fno-strict-aliasing")
+        .flag_if_supported("-fomit-frame-pointer")
+        .flag_if_supported("-ffunction-sections")
+        .flag_if_supported("-fdata-sections")
+        .compile("pqcrystals_kyber_ref");
+}
+
+/// Builds `transpilation/ffi/*.c`, exposed by the `transpiled` module.
+fn transpiled_c() {
+    let sources = ["transpilation/ffi/rust_ntt_to_c.c", "transpilation/ffi/ntt_asm_to_c.c"];
+    for source in sources.iter() {
+        println!("cargo:rerun-if-changed={}", source);
+    }
+    cc::Build::new()
+        .files(sources.iter())
+        .opt_level(3)
+        // the translations are kept as generated, warnings included
+        .warnings(false)
+        .compile("transpiled_ntt");
+}
+
+/// Writes `kat_vectors.rs` with the first `KAT_VECTORS` entries of the round 3
+/// `.rsp` file of the selected parameter set, named after its secret key size
+/// as in the NIST submission package (`kat/PQCkemKAT_3168.rsp` for Kyber1024).
+fn kat_vectors(out: &Path) {
+    let sk_bytes = if env::var_os("CARGO_FEATURE_KYBER512").is_some() {
+        1632
+    } else if env::var_os("CARGO_FEATURE_KYBER1024").is_some() {
+        3168
+    } else {
+        2400
+    };
+    let rsp = format!("kat/PQCkemKAT_{}.rsp", sk_bytes);
+    println!("cargo:rerun-if-changed={}", rsp);
+    println!("cargo:rerun-if-env-changed=KAT_VECTORS");
+
+    let limit = env::var("KAT_VECTORS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_KAT_VECTORS);
+
+    let mut table = String::from("pub const VECTORS: &[Kat] = &[\n");
+    match fs::read_to_string(&rsp) {
+        Ok(content) => {
+            for entry in content.split("count = ").skip(1).take(limit) {
+                write_kat(&mut table, entry);
+            }
+        }
+        Err(_) => println!("cargo:warning={} not found, the KAT example has no vectors", rsp),
+    }
+    table.push_str("];\n");
+    fs::write(out.join("kat_vectors.rs"), table).unwrap();
+}
+
+fn write_kat(table: &mut String, entry: &str) {
+    let count = entry.lines().next().unwrap().trim();
+    write!(table, "    Kat {{\n        count: {},\n", count).unwrap();
+    for field in ["seed", "pk", "sk", "ct", "ss"] {
+        let prefix = format!("{} = ", field);
+        let hex = entry
+            .lines()
+            .find_map(|l| l.strip_prefix(&prefix))
+            .unwrap_or_else(|| panic!("KAT entry {} has no `{}`", count, field))
+            .trim();
+        write!(table, "        {}: [", field).unwrap();
+        for i in (0..hex.len()).step_by(2) {
+            write!(table, "0x{},", &hex[i..i + 2]).unwrap();
+        }
+        table.push_str("],\n");
+    }
+    table.push_str("    },\n");
 }
@@ -0,0 +1,15 @@
+/* Statics placed with `#[link_section = ".ccram"]` (see `src/ccram.rs`). */
+/* CCRAM is not reachable by the instruction bus: data only. The initial */
+/* values live in FLASH and are copied by `ccram::init`. */
+SECTIONS
+{
+  .ccram : ALIGN(4)
+  {
+    __sccram = .;
+    *(.ccram .ccram.*);
+    . = ALIGN(4);
+    __eccram = .;
+  } > CCRAM AT > FLASH
+
+  __siccram = LOADADDR(.ccram);
+} INSERT AFTER .bss;
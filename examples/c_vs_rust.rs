@@ -0,0 +1,169 @@
+//! pqc_kyber against the pqcrystals C reference on the same board, clock and
+//! counter: keypair, encapsulation, decapsulation and the NTT.
+//!
+//! Needs the `c-reference` feature and the C sources, see build.rs.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::{entry, exception};
+use cortex_m_semihosting::hprintln;
+
+use kyber_rust::bench::{bench, Stats, TABLE_HEADER};
+use kyber_rust::board::{self, BoardRng};
+use kyber_rust::c_ref;
+use kyber_rust::counter::{self, CycleCounter};
+use kyber_rust::exit;
+use kyber_rust::stack;
+use kyber_rust::PARAMETER_SET;
+use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, ntt, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+
+const ITERATIONS: usize = 1_000;
+const WARMUP: usize = 10;
+
+/// Deterministic coins, the same on both sides: pqc_kyber gets them as seeds,
+/// the C reference through `randombytes`.
+const KEYPAIR_SEED: ([u8; 32], [u8; 32]) = ([1u8; 32], [255u8; 32]);
+const ENCAPS_SEED: [u8; 32] = [255u8; 32];
+
+struct Kem {
+    pk: [u8; KYBER_PUBLICKEYBYTES],
+    sk: [u8; KYBER_SECRETKEYBYTES],
+    ct: [u8; KYBER_CIPHERTEXTBYTES],
+    ss: [u8; KYBER_SSBYTES],
+}
+
+impl Kem {
+    fn new() -> Kem {
+        Kem {
+            pk: [0; KYBER_PUBLICKEYBYTES],
+            sk: [0; KYBER_SECRETKEYBYTES],
+            ct: [0; KYBER_CIPHERTEXTBYTES],
+            ss: [0; KYBER_SSBYTES],
+        }
+    }
+}
+
+fn bench_rust<C: CycleCounter>(counter: &mut C, rng: &mut BoardRng, kem: &mut Kem) -> [Stats; 3] {
+    let Kem { pk, sk, ct, ss } = kem;
+    let mut keypair_op = || {
+        let bufs = Some((KEYPAIR_SEED.0.as_slice(), KEYPAIR_SEED.1.as_slice()));
+        crypto_kem_keypair(pk, sk, rng, bufs)
+    };
+    // checked once outside the timed runs, which discard the results
+    exit::check("pqc_kyber keypair", keypair_op());
+    let keypair = bench(counter, "keypair (Rust)", ITERATIONS, WARMUP, &mut keypair_op);
+    let (result, keypair_stack) = stack::measure(&mut keypair_op);
+    exit::check("pqc_kyber keypair", result);
+
+    let mut enc_op = || crypto_kem_enc(ct, ss, pk, rng, Some(ENCAPS_SEED.as_slice()));
+    exit::check("pqc_kyber encapsulation", enc_op());
+    let enc = bench(counter, "enc (Rust)", ITERATIONS, WARMUP, &mut enc_op);
+    let (result, enc_stack) = stack::measure(&mut enc_op);
+    exit::check("pqc_kyber encapsulation", result);
+
+    let mut ss_dec = [0u8; KYBER_SSBYTES];
+    let mut dec_op = || crypto_kem_dec(&mut ss_dec, ct, sk);
+    let dec = bench(counter, "dec (Rust)", ITERATIONS, WARMUP, &mut dec_op);
+    let (_, dec_stack) = stack::measure(&mut dec_op);
+
+    assert_eq!(*ss, ss_dec, "pqc_kyber shared secrets do not match");
+    [
+        keypair.with_stack(keypair_stack),
+        enc.with_stack(enc_stack),
+        dec.with_stack(dec_stack),
+    ]
+}
+
+fn bench_c<C: CycleCounter>(counter: &mut C, kem: &mut Kem) -> [Stats; 3] {
+    let Kem { pk, sk, ct, ss } = kem;
+    let mut coins = [0u8; 64];
+    coins[..32].copy_from_slice(&KEYPAIR_SEED.0);
+    coins[32..].copy_from_slice(&KEYPAIR_SEED.1);
+    c_ref::replay_randombytes(&coins);
+    let mut keypair_op = || assert_eq!(c_ref::keypair(pk, sk), 0, "C keypair failed");
+    let keypair = bench(counter, "keypair (C)", ITERATIONS, WARMUP, &mut keypair_op);
+    let (_, keypair_stack) = stack::measure(&mut keypair_op);
+
+    c_ref::replay_randombytes(&ENCAPS_SEED);
+    let mut enc_op = || assert_eq!(c_ref::enc(ct, ss, pk), 0, "C encapsulation failed");
+    let enc = bench(counter, "enc (C)", ITERATIONS, WARMUP, &mut enc_op);
+    let (_, enc_stack) = stack::measure(&mut enc_op);
+
+    let mut ss_dec = [0u8; KYBER_SSBYTES];
+    let mut dec_op = || assert_eq!(c_ref::dec(&mut ss_dec, ct, sk), 0, "C decapsulation failed");
+    let dec = bench(counter, "dec (C)", ITERATIONS, WARMUP, &mut dec_op);
+    let (_, dec_stack) = stack::measure(&mut dec_op);
+
+    assert_eq!(*ss, ss_dec, "C shared secrets do not match");
+    [
+        keypair.with_stack(keypair_stack),
+        enc.with_stack(enc_stack),
+        dec.with_stack(dec_stack),
+    ]
+}
+
+fn bench_ntt<C: CycleCounter>(counter: &mut C) -> [Stats; 2] {
+    let mut a = [0i16; 256];
+    for (i, a) in a.iter_mut().enumerate() {
+        *a = (i as i16 * 13) % 3329 - 1664;
+    }
+    let rust_op = || {
+        let mut r = a;
+        ntt(&mut r);
+        r
+    };
+    let c_op = || {
+        let mut r = a;
+        c_ref::ntt(&mut r);
+        r
+    };
+    hprintln!("ntt outputs match: {}", rust_op() == c_op());
+    [
+        bench(counter, "ntt (Rust)", ITERATIONS, WARMUP, rust_op),
+        bench(counter, "ntt (C)", ITERATIONS, WARMUP, c_op),
+    ]
+}
+
+fn compare<C: CycleCounter>(counter: &mut C, name: &str, rng: &mut BoardRng) {
+    let mut rust = Kem::new();
+    let mut c = Kem::new();
+    let [rust_keypair, rust_enc, rust_dec] = bench_rust(counter, rng, &mut rust);
+    let [c_keypair, c_enc, c_dec] = bench_c(counter, &mut c);
+    let [rust_ntt, c_ntt] = bench_ntt(counter);
+
+    // same coins on both sides, so the same keys and ciphertext
+    let identical = rust.pk == c.pk && rust.sk == c.sk && rust.ct == c.ct && rust.ss == c.ss;
+    hprintln!("pqc_kyber and C outputs are identical: {}", identical);
+
+    // same formats on both sides: pqc_kyber opens what the C code sealed
+    let mut ss = [0u8; KYBER_SSBYTES];
+    crypto_kem_dec(&mut ss, &c.ct, &c.sk);
+    hprintln!("pqc_kyber decapsulates the C ciphertext: {}", ss == c.ss);
+
+    hprintln!("{} cycles, {} iterations, stack in bytes", name, ITERATIONS);
+    hprintln!("{}", TABLE_HEADER);
+    for s in [rust_keypair, c_keypair, rust_enc, c_enc, rust_dec, c_dec, rust_ntt, c_ntt].iter() {
+        hprintln!("{}", s.row());
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("Clock: {}", board::CLOCK);
+    hprintln!("Parameter set: {}", PARAMETER_SET);
+
+    if let Some(dwt) = board.dwt.as_mut() {
+        compare(dwt, "DWT", &mut board.rng);
+    }
+    compare(&mut board.systick, "SysTick", &mut board.rng);
+    exit::success()
+}
+
+#[exception]
+#[allow(non_snake_case)]
+fn SysTick() {
+    counter::systick_wrapped();
+}
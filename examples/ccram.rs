@@ -0,0 +1,92 @@
+//! Cycles of the NTT, inverse NTT and base multiplication of pqc_kyber with
+//! their polynomials in RAM and in CCRAM.
+//!
+//! The table only moves the operands; build with `--features ccram-stack` to
+//! also move the stack (run `speed_evaluation` with and without it for the
+//! KEM operations, whose polynomials all live on the stack).
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::{entry, exception};
+use cortex_m_semihosting::hprintln;
+
+use kyber_rust::bench::{bench, Stats, TABLE_HEADER};
+use kyber_rust::board;
+use kyber_rust::exit;
+use kyber_rust::counter::{self, CycleCounter};
+use kyber_rust::poly::poly_basemul;
+use pqc_kyber::{invntt, ntt};
+
+const ITERATIONS: usize = 1_000;
+const WARMUP: usize = 10;
+
+/// Input, operand and result polynomials in the default RAM.
+static mut RAM_POLYS: [[i16; 256]; 3] = [[0; 256]; 3];
+
+/// The same in CCRAM, whatever the `ccram` feature says.
+#[link_section = ".ccram"]
+static mut CCRAM_POLYS: [[i16; 256]; 3] = [[0; 256]; 3];
+
+fn bench_polys<C: CycleCounter>(counter: &mut C, polys: &mut [[i16; 256]; 3], names: [&'static str; 3]) -> [Stats; 3] {
+    let [a, b, r] = polys;
+    for (i, (a, b)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+        *a = (i as i16 * 13) % 3329 - 1664;
+        *b = (i as i16 * 29) % 3329 - 1664;
+    }
+
+    // the transforms work in place, so each call starts from a copy of `a`
+    let ntt_op = || {
+        *r = *a;
+        ntt(r);
+    };
+    let ntt = bench(counter, names[0], ITERATIONS, WARMUP, ntt_op);
+    let invntt_op = || {
+        *r = *a;
+        invntt(r);
+    };
+    let invntt = bench(counter, names[1], ITERATIONS, WARMUP, invntt_op);
+    let basemul_op = || poly_basemul(r, a, b);
+    let basemul = bench(counter, names[2], ITERATIONS, WARMUP, basemul_op);
+    [ntt, invntt, basemul]
+}
+
+fn bench_placements<C: CycleCounter>(counter: &mut C, name: &str) {
+    let ram = unsafe { &mut *core::ptr::addr_of_mut!(RAM_POLYS) };
+    let ccram = unsafe { &mut *core::ptr::addr_of_mut!(CCRAM_POLYS) };
+    hprintln!("{} cycles, {} iterations", name, ITERATIONS);
+    hprintln!("{}", TABLE_HEADER);
+    for stats in [
+        bench_polys(counter, ram, ["ntt (RAM)", "invntt (RAM)", "basemul (RAM)"]),
+        bench_polys(counter, ccram, ["ntt (CCRAM)", "invntt (CCRAM)", "basemul (CCRAM)"]),
+    ] {
+        for s in &stats {
+            hprintln!("{}", s.row());
+        }
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("Clock: {}", board::CLOCK);
+    hprintln!(
+        "Polynomials at {:p} (RAM) and {:p} (CCRAM), stack in {}",
+        core::ptr::addr_of!(RAM_POLYS),
+        core::ptr::addr_of!(CCRAM_POLYS),
+        if cfg!(feature = "ccram-stack") { "CCRAM" } else { "RAM" }
+    );
+
+    if let Some(dwt) = board.dwt.as_mut() {
+        bench_placements(dwt, "DWT");
+    }
+    bench_placements(&mut board.systick, "SysTick");
+    exit::success()
+}
+
+#[exception]
+#[allow(non_snake_case)]
+fn SysTick() {
+    counter::systick_wrapped();
+}
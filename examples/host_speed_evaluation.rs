@@ -0,0 +1,83 @@
+//! `speed_evaluation` built for the host: the same KEM benchmarks, and the NTT
+//! ones with `m4-dsp-ntt`, timed with the time-stamp counter on x86-64 (with
+//! `std::time::Instant` elsewhere) and written to the same JSON and CSV
+//! records as the firmware, next to the desktop listings in `assembly/`.
+//!
+//! `cargo host --release --example host_speed_evaluation [--features kyber1024]`
+
+use kyber_rust::bench::{Stats, TABLE_HEADER};
+use kyber_rust::clock::ClockProfile;
+use kyber_rust::counter::CycleCounter;
+use kyber_rust::exit;
+use kyber_rust::report::{Format, Sink};
+use kyber_rust::rng::SeededRng;
+use kyber_rust::suite::{self, ITERATIONS};
+use kyber_rust::{OPT_LEVEL, PARAMETER_SET};
+
+/// Files the results are written to, in the working directory.
+const RESULTS: [(&str, Format); 2] = [
+    ("host_speed_evaluation.json", Format::Json),
+    ("host_speed_evaluation.csv", Format::Csv),
+];
+
+#[cfg(target_arch = "x86_64")]
+fn counter() -> (kyber_rust::counter::RdtscCounter, &'static str, ClockProfile) {
+    let counter = kyber_rust::counter::RdtscCounter::new();
+    let hz = counter.frequency().min(u32::MAX as u64) as u32;
+    (counter, "rdtsc", ClockProfile::host("host-tsc", hz))
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn counter() -> (kyber_rust::counter::StdCounter, &'static str, ClockProfile) {
+    (kyber_rust::counter::StdCounter::new(), "Instant", ClockProfile::host("host-ns", 1_000_000_000))
+}
+
+/// No stack painting on the host.
+fn no_stack(_: &mut dyn FnMut()) -> Option<usize> {
+    None
+}
+
+fn report(counter: &str, clock: ClockProfile, stats: &[Stats], sinks: &mut [Option<Sink>]) {
+    println!("{} ticks, {} iterations", counter, ITERATIONS);
+    println!("{}", TABLE_HEADER);
+    for s in stats {
+        println!("{}", s.row());
+    }
+    for s in stats {
+        println!("{:<24} mean {} us at {} MHz", s.name, clock.cycles_to_us(s.mean), clock.sysclk_hz / 1_000_000);
+    }
+    for sink in sinks.iter_mut().flatten() {
+        if let Err(e) = sink.record(counter, stats) {
+            println!("Writing the results failed: {:?}", e);
+        }
+    }
+}
+
+fn main() {
+    let (mut counter, name, clock) = counter();
+    println!("Board: host ({})", std::env::consts::ARCH);
+    println!("Clock: {}, {} MHz", clock.name, clock.sysclk_hz / 1_000_000);
+    println!("Parameter set: {}", PARAMETER_SET);
+    println!("Opt-level: {}", OPT_LEVEL);
+
+    let mut sinks = RESULTS.map(|(path, format)| match Sink::create(path, format, clock) {
+        Ok(sink) => Some(sink),
+        Err(e) => {
+            println!("Not writing {}: {:?}", path, e);
+            None
+        }
+    });
+
+    let mut rng = SeededRng::default();
+    println!("Counter overhead: {} {}", name, counter.overhead());
+    report(name, clock, &suite::kem(&mut counter, &mut rng, no_stack), &mut sinks);
+    #[cfg(feature = "m4-dsp-ntt")]
+    report(name, clock, &suite::ntt(&mut counter), &mut sinks);
+    for sink in IntoIterator::into_iter(sinks).flatten() {
+        if let Err(e) = sink.finish() {
+            println!("Writing the results failed: {:?}", e);
+        }
+    }
+    println!("Shared secrets match");
+    exit::success()
+}
@@ -0,0 +1,79 @@
+//! Known-Answer-Tests: pqc_kyber against the NIST round 3 vectors in `kat/`,
+//! on the board.
+//!
+//! Every vector replays the reference `randombytes` calls from its DRBG seed
+//! (see `kyber_rust::kat`) and compares pk, sk, ct and both shared secrets.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::{entry, exception};
+use cortex_m_semihosting::hprintln;
+
+use kyber_rust::board;
+use kyber_rust::exit;
+use kyber_rust::counter;
+use kyber_rust::kat::{Drbg, VECTORS};
+use kyber_rust::PARAMETER_SET;
+use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+
+#[entry]
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("{} KAT, {} vectors", PARAMETER_SET, VECTORS.len());
+
+    let mut failures = 0;
+    for kat in VECTORS {
+        // same order of `randombytes` calls as the reference implementation
+        let mut drbg = Drbg::new(&kat.seed);
+        let mut d = [0u8; 32];
+        let mut z = [0u8; 32];
+        let mut m = [0u8; 32];
+        drbg.fill(&mut d);
+        drbg.fill(&mut z);
+        drbg.fill(&mut m);
+
+        let mut pk = [0u8; KYBER_PUBLICKEYBYTES];
+        let mut sk = [0u8; KYBER_SECRETKEYBYTES];
+        let mut ct = [0u8; KYBER_CIPHERTEXTBYTES];
+        let mut ss_enc = [0u8; KYBER_SSBYTES];
+        let mut ss_dec = [0u8; KYBER_SSBYTES];
+        if let Err(e) = crypto_kem_keypair(&mut pk, &mut sk, &mut board.rng, Some((&d[..], &z[..]))) {
+            hprintln!("count = {}: FAIL keypair {:?}", kat.count, e);
+            failures += 1;
+            continue;
+        }
+        if let Err(e) = crypto_kem_enc(&mut ct, &mut ss_enc, &pk, &mut board.rng, Some(&m[..])) {
+            hprintln!("count = {}: FAIL encapsulation {:?}", kat.count, e);
+            failures += 1;
+            continue;
+        }
+        crypto_kem_dec(&mut ss_dec, &ct, &sk);
+
+        let checks = [
+            ("pk", pk[..] == kat.pk[..]),
+            ("sk", sk[..] == kat.sk[..]),
+            ("ct", ct[..] == kat.ct[..]),
+            ("ss (enc)", ss_enc == kat.ss),
+            ("ss (dec)", ss_dec == kat.ss),
+        ];
+        if checks.iter().all(|&(_, ok)| ok) {
+            hprintln!("count = {}: PASS", kat.count);
+        } else {
+            failures += 1;
+            for &(field, _) in checks.iter().filter(|&&(_, ok)| !ok) {
+                hprintln!("count = {}: FAIL {}", kat.count, field);
+            }
+        }
+    }
+
+    hprintln!("{} passed, {} failed", VECTORS.len() - failures, failures);
+    exit::status(failures == 0 && !VECTORS.is_empty())
+}
+
+#[exception]
+#[allow(non_snake_case)]
+fn SysTick() {
+    counter::systick_wrapped();
+}
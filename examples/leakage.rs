@@ -0,0 +1,149 @@
+//! dudect-style timing leakage assessment of `crypto_kem_dec`.
+//!
+//! Two tests, each interleaving its two input classes in random order:
+//! - valid ciphertexts (class 0) against tampered ones that go through the
+//!   implicit rejection (class 1), under a fixed key pair;
+//! - a fixed secret key (class 0) against a fresh one per call (class 1).
+//!
+//! Both classes prepare their inputs with the same work before the timed
+//! call. Each call is timed and fed to an online Welch's t-test; `|t| > 4.5`
+//! is reported as leakage and makes the program exit with a failure.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::{entry, exception};
+use cortex_m_semihosting::hprintln;
+use rand_core::RngCore;
+
+use kyber_rust::board::{self, BoardRng};
+use kyber_rust::exit;
+use kyber_rust::counter::{self, CycleCounter};
+use kyber_rust::leakage::{self, WelchTest, THRESHOLD};
+use kyber_rust::PARAMETER_SET;
+use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+
+/// Timed decapsulations per test, both classes together.
+const MEASUREMENTS: usize = 10_000;
+
+/// Interval at which the running statistic is printed.
+const REPORT_EVERY: usize = 1_000;
+
+fn class(rng: &mut BoardRng) -> usize {
+    (rng.next_u32() & 1) as usize
+}
+
+fn report(name: &str, test: &WelchTest) {
+    hprintln!(
+        "{}: {} / {} measurements, mean {:.0} / {:.0} cycles, t = {:.2}",
+        name,
+        test.count(0),
+        test.count(1),
+        test.mean(0),
+        test.mean(1),
+        test.t()
+    );
+}
+
+/// Valid against tampered ciphertexts under one key pair.
+fn ciphertext_validity<C: CycleCounter>(counter: &mut C, rng: &mut BoardRng) -> WelchTest {
+    let mut pk = [0u8; KYBER_PUBLICKEYBYTES];
+    let mut sk = [0u8; KYBER_SECRETKEYBYTES];
+    crypto_kem_keypair(&mut pk, &mut sk, rng, None).expect("key pair generation failed");
+
+    let mut test = WelchTest::new();
+    let mut ct = [0u8; KYBER_CIPHERTEXTBYTES];
+    let mut ss_enc = [0u8; KYBER_SSBYTES];
+    let mut ss_dec = [0u8; KYBER_SSBYTES];
+    for i in 1..=MEASUREMENTS {
+        let class = class(rng);
+        crypto_kem_enc(&mut ct, &mut ss_enc, &pk, rng, None).expect("encapsulation failed");
+        // same draws for both classes, only class 1 flips the bit
+        let byte = rng.next_u32() as usize % ct.len();
+        ct[byte] ^= (class as u8) << (rng.next_u32() % 8);
+
+        leakage::measure(counter, &mut test, class, || crypto_kem_dec(&mut ss_dec, &ct, &sk));
+        assert_eq!(ss_enc == ss_dec, class == 0, "implicit rejection did not behave as expected");
+
+        if i % REPORT_EVERY == 0 {
+            report("valid vs tampered ciphertext", &test);
+        }
+    }
+    test
+}
+
+/// A fixed secret key against a fresh one for every call.
+///
+/// Both classes generate a fresh key pair, copy the one they use into the
+/// working buffers and encapsulate under it, so that the preparation leaves
+/// the same state behind whatever the class.
+fn secret_key<C: CycleCounter>(counter: &mut C, rng: &mut BoardRng) -> WelchTest {
+    let mut fixed_pk = [0u8; KYBER_PUBLICKEYBYTES];
+    let mut fixed_sk = [0u8; KYBER_SECRETKEYBYTES];
+    crypto_kem_keypair(&mut fixed_pk, &mut fixed_sk, rng, None).expect("key pair generation failed");
+
+    let mut test = WelchTest::new();
+    let mut fresh_pk = [0u8; KYBER_PUBLICKEYBYTES];
+    let mut fresh_sk = [0u8; KYBER_SECRETKEYBYTES];
+    let mut pk = [0u8; KYBER_PUBLICKEYBYTES];
+    let mut sk = [0u8; KYBER_SECRETKEYBYTES];
+    let mut ct = [0u8; KYBER_CIPHERTEXTBYTES];
+    let mut ss_enc = [0u8; KYBER_SSBYTES];
+    let mut ss_dec = [0u8; KYBER_SSBYTES];
+    for i in 1..=MEASUREMENTS {
+        let class = class(rng);
+        crypto_kem_keypair(&mut fresh_pk, &mut fresh_sk, rng, None).expect("key pair generation failed");
+        let (src_pk, src_sk) = if class == 0 { (&fixed_pk, &fixed_sk) } else { (&fresh_pk, &fresh_sk) };
+        pk.copy_from_slice(src_pk);
+        sk.copy_from_slice(src_sk);
+        crypto_kem_enc(&mut ct, &mut ss_enc, &pk, rng, None).expect("encapsulation failed");
+
+        leakage::measure(counter, &mut test, class, || crypto_kem_dec(&mut ss_dec, &ct, &sk));
+        assert_eq!(ss_enc, ss_dec, "shared secrets do not match");
+
+        if i % REPORT_EVERY == 0 {
+            report("fixed vs random secret key", &test);
+        }
+    }
+    test
+}
+
+/// Runs both tests, returns `true` if any of them exceeds the threshold.
+fn assess<C: CycleCounter>(counter: &mut C, rng: &mut BoardRng) -> bool {
+    let mut leaks = false;
+    for (name, test) in [
+        ("valid vs tampered ciphertext", ciphertext_validity(counter, rng)),
+        ("fixed vs random secret key", secret_key(counter, rng)),
+    ] {
+        let verdict = if test.leaks() { "LEAKAGE" } else { "no leakage detected" };
+        hprintln!("{}: t = {:.2} (threshold |t| > {}): {}", name, test.t(), THRESHOLD, verdict);
+        leaks |= test.leaks();
+    }
+    leaks
+}
+
+#[entry]
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("Clock: {}", board::CLOCK);
+    hprintln!("Parameter set: {}", PARAMETER_SET);
+
+    let leaks = match board.dwt.as_mut() {
+        Some(dwt) => {
+            hprintln!("Counter: DWT");
+            assess(dwt, &mut board.rng)
+        }
+        None => {
+            hprintln!("Counter: SysTick");
+            assess(&mut board.systick, &mut board.rng)
+        }
+    };
+    exit::status(!leaks)
+}
+
+#[exception]
+#[allow(non_snake_case)]
+fn SysTick() {
+    counter::systick_wrapped();
+}
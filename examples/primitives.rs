@@ -0,0 +1,114 @@
+//! Cycle counts of the building blocks of pqc_kyber, to attribute the cost of
+//! the KEM operations measured by `speed_evaluation` to its parts.
+//!
+//! Every closure starts from the same input, so the `ntt`, `invntt`,
+//! `poly_tomont` and sampling figures include copying one polynomial
+//! (a few dozen cycles).
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::{entry, exception};
+use cortex_m_semihosting::hprintln;
+
+use kyber_rust::bench::{bench, Stats, TABLE_HEADER};
+use kyber_rust::board;
+use kyber_rust::exit;
+use kyber_rust::counter::{self, CycleCounter};
+use kyber_rust::PARAMETER_SET;
+use pqc_kyber::{
+    invntt, kyber_shake128_absorb, kyber_shake128_squeezeblocks, ntt, poly_basemul, poly_getnoise_eta1,
+    poly_getnoise_eta2, poly_tomont, shake256_prf, KeccakState, Poly, KYBER_K, KYBER_SYMBYTES, SHAKE128_RATE,
+};
+
+const ITERATIONS: usize = 1_000;
+const WARMUP: usize = 10;
+
+/// Seed of the matrix and noise samplers.
+const SEED: [u8; KYBER_SYMBYTES] = [42u8; KYBER_SYMBYTES];
+
+/// Output of the `prf` call in `poly_getnoise_eta1`, `KYBER_ETA1 * KYBER_N / 4`
+/// bytes: 192 for Kyber512 (eta1 = 3), 128 for Kyber768 and Kyber1024.
+const PRF_BYTES: usize = (if KYBER_K == 2 { 3 } else { 2 }) * 256 / 4;
+
+fn bench_primitives<C: CycleCounter>(counter: &mut C) -> [Stats; 9] {
+    let mut a = Poly::new();
+    let mut b = Poly::new();
+    for (i, (a, b)) in a.coeffs.iter_mut().zip(b.coeffs.iter_mut()).enumerate() {
+        *a = (i as i16 * 13) % 3329 - 1664;
+        *b = (i as i16 * 29) % 3329 - 1664;
+    }
+
+    let ntt_op = || {
+        let mut r = a.coeffs;
+        ntt(&mut r);
+        r
+    };
+    let ntt = bench(counter, "ntt", ITERATIONS, WARMUP, ntt_op);
+    let invntt_op = || {
+        let mut r = a.coeffs;
+        invntt(&mut r);
+        r
+    };
+    let invntt = bench(counter, "invntt", ITERATIONS, WARMUP, invntt_op);
+    let mut r = Poly::new();
+    let basemul_op = || poly_basemul(&mut r, &a, &b);
+    let basemul = bench(counter, "poly_basemul", ITERATIONS, WARMUP, basemul_op);
+    let tomont_op = || {
+        let mut r = a;
+        poly_tomont(&mut r);
+        r
+    };
+    let tomont = bench(counter, "poly_tomont", ITERATIONS, WARMUP, tomont_op);
+
+    // PRF and CBD, as for the secret and error polynomials
+    let mut noise = Poly::new();
+    let eta1_op = || poly_getnoise_eta1(&mut noise, &SEED, 0);
+    let eta1 = bench(counter, "poly_getnoise_eta1", ITERATIONS, WARMUP, eta1_op);
+    let eta2_op = || poly_getnoise_eta2(&mut noise, &SEED, 0);
+    let eta2 = bench(counter, "poly_getnoise_eta2", ITERATIONS, WARMUP, eta2_op);
+
+    // the matrix sampling in gen_matrix: absorb seed || i || j, then squeeze
+    let mut state = KeccakState::new();
+    let absorb_op = || kyber_shake128_absorb(&mut state, &SEED, 0, 0);
+    let absorb = bench(counter, "shake128_absorb", ITERATIONS, WARMUP, absorb_op);
+    let mut block = [0u8; SHAKE128_RATE];
+    let squeeze_op = || kyber_shake128_squeezeblocks(&mut block, 1, &mut state);
+    let squeeze = bench(counter, "shake128_squeezeblock", ITERATIONS, WARMUP, squeeze_op);
+
+    let mut prf = [0u8; PRF_BYTES];
+    let prf_op = || shake256_prf(&mut prf, PRF_BYTES, &SEED, 0);
+    let shake256 = bench(counter, "shake256_prf", ITERATIONS, WARMUP, prf_op);
+
+    [ntt, invntt, basemul, tomont, eta1, eta2, absorb, squeeze, shake256]
+}
+
+fn print_table(counter: &str, stats: &[Stats]) {
+    hprintln!("{} cycles, {} iterations", counter, ITERATIONS);
+    hprintln!("{}", TABLE_HEADER);
+    for s in stats {
+        hprintln!("{}", s.row());
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("Clock: {}", board::CLOCK);
+    hprintln!("Parameter set: {}", PARAMETER_SET);
+
+    if let Some(dwt) = board.dwt.as_mut() {
+        hprintln!("Counter overhead: DWT {}", dwt.overhead());
+        print_table("DWT", &bench_primitives(dwt));
+    }
+    hprintln!("Counter overhead: SysTick {}", board.systick.overhead());
+    print_table("SysTick", &bench_primitives(&mut board.systick));
+    exit::success()
+}
+
+#[exception]
+#[allow(non_snake_case)]
+fn SysTick() {
+    counter::systick_wrapped();
+}
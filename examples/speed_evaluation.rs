@@ -1,231 +1,79 @@
 #![no_main]
 #![no_std]
 
-use panic_halt as _;
 use cortex_m_rt::{entry, exception};
-use cortex_m_semihosting::{hprint, hprintln};
-// use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
-
-use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, Poly, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_Q, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
-use core::cell::RefCell;
-
-// use cortex_m::{
-//     peripheral::{syst::SystClkSource, SYST}, Peripherals,
-// };
-use cortex_m::{interrupt::Mutex, peripheral::{syst::SystClkSource, Peripherals, DWT}};
-use stm32f4xx_hal::{
-    pac::{self, rcc, SYST},
-    prelude::*,
-    rcc::Clocks,
-};
-use core::sync::atomic::{compiler_fence, Ordering};
-
-struct Instant {
-    clock_ticks: u64,
-    milliseconds: u64
+use cortex_m_semihosting::hprintln;
+
+use kyber_rust::bench::{Stats, TABLE_HEADER};
+use kyber_rust::board;
+use kyber_rust::counter::{self, CycleCounter};
+use kyber_rust::exit;
+use kyber_rust::report::{Format, Sink};
+use kyber_rust::stack;
+use kyber_rust::suite::{self, ITERATIONS};
+use kyber_rust::PARAMETER_SET;
+
+/// Peak stack usage of `op`, run on a freshly painted stack.
+fn measure_stack(op: &mut dyn FnMut()) -> Option<usize> {
+    Some(stack::measure(op).1)
 }
 
-struct TickCounter {
-    #[allow(dead_code)]
-    /// keep syst as a resource
-    syst: SYST,
-    period: u64,
-    clock_ticks: u64,
-}
-
-impl TickCounter{
-    pub fn new(mut syst: SYST, clocks: &Clocks) -> TickCounter {
-        syst.set_clock_source(SystClkSource::Core);
-        let fraction: u32 = 10; // 24_000_000 / 100_000 => each tick is a nanosecond, after the tick the counter is reloaded
-        //let fraction: u32 = 1_000_000; // 8_000_000 / 100_000 => each tick is a nanosecond
-        hprintln!("Launch an interrupt every {:?} ticks", clocks.sysclk() - 1.Hz());
-
-        // configures the system timer to trigger a SysTick exception every (24_000_000 / x) second
-        // syst.set_reload(clocks.sysclk().0 / 1_000 - 1); // To make the timer wrap every N ticks set the reload value to N - 1
-        syst.set_reload((clocks.sysclk().to_Hz() / 10) - 1); // To make the timer wrap every N ticks set the reload value to N - 1
-        hprintln!("Current {:?} ticks", SYST::get_current());
-        syst.clear_current();
-        syst.enable_counter();
-        syst.enable_interrupt();
+/// Host files the results are written to, next to the semihosting console.
+const RESULTS: [(&str, Format); 2] = [("speed_evaluation.json", Format::Json), ("speed_evaluation.csv", Format::Csv)];
 
-        hprintln!("Current {:?} ticks", SYST::get_current());
-        hprintln!("Reload after {:?} ticks", SYST::get_reload());
-        hprintln!("10 milliseconds are {:?} ticks", SYST::get_ticks_per_10ms());
-        hprintln!("Current {:?} ticks", SYST::get_current());
-        hprintln!("Is precise ? {:?}", SYST::is_precise());
-
-        TickCounter {
-            syst,
-            period: clocks.sysclk().to_Hz() as u64 / fraction as u64,
-            clock_ticks: 0
-        }
+fn report(counter: &str, stats: &[Stats], sinks: &mut [Option<Sink>]) {
+    hprintln!("{} cycles, {} iterations, stack in bytes", counter, ITERATIONS);
+    hprintln!("{}", TABLE_HEADER);
+    for s in stats {
+        hprintln!("{}", s.row());
     }
-
-    pub fn clock_tick(&mut self) {
-        self.clock_ticks += self.period;
-    }
-
-    pub fn get_milliseconds(&self) -> u64 {
-        self.clock_ticks / self.period
-            // + ((self.syst.cvr.read() as u64) / self.period)
+    for s in stats {
+        hprintln!("{:<24} mean {} us at {} MHz", s.name, board::CLOCK.cycles_to_us(s.mean), board::CLOCK.sysclk_hz / 1_000_000);
     }
-
-    pub fn get_clock_ticks(&self) -> u64 {
-        self.clock_ticks
-            // + (self.syst.cvr.read() as u64)
-    }
-
-    pub fn reset(&mut self) {
-        self.clock_ticks = 0;
-    }
-}
-
-static TICK_COUNTER: Mutex<RefCell<Option<TickCounter>>> = Mutex::new(RefCell::new(None));
-
-fn instant() -> Instant {
-    cortex_m::interrupt::free(|cs| {
-        if let Some(counter) = &*TICK_COUNTER.borrow(cs).borrow_mut() {
-            Instant {
-                clock_ticks: counter.get_clock_ticks(),
-                milliseconds: counter.get_milliseconds()
-            }
-        } else {
-            Instant {
-                clock_ticks: 0,
-                milliseconds: 0
-            }
-        }
-    })
-}
-
-fn reset() {
-    cortex_m::interrupt::free(|cs| {
-        if let Some(counter) = &mut*TICK_COUNTER.borrow(cs).borrow_mut() {
-            counter.reset();
+    for sink in sinks.iter_mut().flatten() {
+        if let Err(e) = sink.record(counter, stats) {
+            hprintln!("Writing the results failed: {:?}", e);
         }
-    })
+    }
 }
 
-// #[entry]
-
-
 #[entry]
-unsafe fn main() -> ! {
-    let dp = pac::Peripherals::take().unwrap();
-    let rcc = dp.RCC.constrain();
-    let clocks = rcc
-        .cfgr
-        .use_hse(8.MHz()) //discovery board has 8 MHz crystal for HSE
-        .sysclk(24.MHz())
-        .require_pll48clk()
-        .freeze();
-    let mut rng = dp.RNG.constrain(&clocks);
-
-    let core_periphs = cortex_m::Peripherals::take().unwrap();
-
-/*    cortex_m::interrupt::free(|cs| {
-        TICK_COUNTER
-        .borrow(cs)
-        .borrow_mut()
-        .replace(TickCounter::new(core_periphs.SYST, &clocks))
-    });*/
-
-    let mut syst = core_periphs.SYST;
-    syst.set_clock_source(SystClkSource::Core);
-    let fraction: u32 = 2; // 24_000_000 / 100_000 => each tick is a nanosecond, after the tick the counter is reloaded
-    //let fraction: u32 = 1_000_000; // 8_000_000 / 100_000 => each tick is a nanosecond
-    hprintln!("Launch an interrupt every {:?} ticks", clocks.sysclk().to_Hz() / 2 - 1);
-
-    // configures the system timer to trigger a SysTick exception every (24_000_000 / x) second
-    // syst.set_reload(clocks.sysclk().0 / 1_000 - 1); // To make the timer wrap every N ticks set the reload value to N - 1
-    syst.set_reload(clocks.sysclk().to_Hz() / fraction - 1);
-    syst.clear_current();
-    syst.enable_counter();
-    syst.enable_interrupt();
-
-    hprintln!("Current {:?} ticks", SYST::get_current());
-    hprintln!("Current {:?} ticks", syst.cvr.read());
-
-    let mut min = u32::MAX;
-    let mut max = u32::MIN;
-    let mut sum = 0;
-    let mut res: [u8;32] = [0u8;32];
-    for _ in 0..1_000 {
-        // let mut pk = [0u8; KYBER_PUBLICKEYBYTES];
-        // let mut sk = [0u8; KYBER_SECRETKEYBYTES];
-        // let bufs = Some(([1u8; 32].as_slice(), [255u8; 32].as_slice()));
-        // let inst0 = syst.cvr.read();
-        // crypto_kem_keypair(&mut pk, &mut sk, &mut rng, bufs);
-        // let inst1 = syst.cvr.read();
-        // let keypair_ticks = if inst0 > inst1 {
-        //     inst0 - inst1 - 2
-        // }else {
-        //     inst0 + (12000000 - inst1)
-        // };
-        // if min >= keypair_ticks {
-        //     min = keypair_ticks;
-        // }else if max <= keypair_ticks {
-        //     max = keypair_ticks;
-        // }
-        // sum += keypair_ticks;
-
-        // /// 1024
-        // let pk = [151, 121, 164, 209, 252, 69, 236, 38, 31, 4, 139, 156, 157, 170, 56, 201, 236, 34, 139, 101, 5, 232, 144, 82, 38, 179, 132, 134, 128, 32, 89, 194, 197, 200, 150, 1, 86, 6, 52, 203, 19, 55, 177, 49, 83, 101, 20, 72, 66, 188, 64, 90, 41, 46, 104, 60, 175, 164, 81, 69, 38, 148, 92, 77, 251, 104, 238, 42, 205, 184, 183, 149, 50, 131, 102, 150, 213, 49, 37, 160, 69, 189, 187, 138, 59, 204, 129, 35, 8, 61, 30, 104, 44, 91, 215, 130, 12, 118, 196, 72, 53, 17, 81, 71, 79, 105, 214, 1, 215, 112, 141, 187, 44, 151, 157, 119, 82, 116, 148, 182, 133, 32, 168, 255, 102, 195, 65, 98, 202, 42, 236, 128, 114, 162, 165, 31, 242, 89, 56, 150, 72, 231, 91, 149, 193, 106, 190, 20, 96, 78, 219, 250, 186, 244, 0, 183, 106, 122, 15, 7, 219, 97, 220, 225, 145, 2, 244, 59, 45, 16, 96, 116, 123, 2, 196, 66, 84, 133, 52, 31, 213, 217, 191, 250, 167, 1, 96, 97, 55, 73, 99, 185, 133, 32, 156, 107, 154, 125, 179, 249, 73, 88, 49, 29, 2, 121, 0, 163, 216, 196, 79, 132, 53, 176, 147, 162, 54, 160, 80, 159, 25, 40, 223, 119, 25, 206, 140, 78, 144, 34, 143, 77, 184, 124, 185, 232, 130, 242, 113, 33, 128, 35, 136, 69, 211, 30, 185, 6, 198, 14, 182, 62, 15, 245, 94, 132, 184, 103, 169, 27, 121, 174, 116, 160, 58, 192, 4, 115, 200, 193, 179, 230, 174, 220, 195, 127, 30, 105, 184, 225, 54, 1, 155, 219, 1, 195, 116, 161, 34, 225, 100, 195, 37, 132, 178, 251, 207, 94, 1, 58, 69, 18, 124, 137, 51, 38, 182, 134, 3, 120, 203, 85, 37, 164, 139, 82, 43, 48, 19, 43, 118, 136, 33, 75, 105, 128, 141, 209, 154, 164, 255, 3, 62, 22, 37, 32, 22, 252, 148, 121, 34, 36, 36, 57, 62, 81, 219, 113, 21, 227, 129, 20, 230, 137, 60, 220, 200, 174, 17, 23, 164, 49, 101, 72, 1, 10, 180, 98, 159, 182, 114, 20, 141, 3, 26, 108, 96, 26, 106, 74, 102, 29, 135, 45, 142, 246, 147, 117, 10, 17, 89, 88, 113, 106, 185, 38, 62, 176, 81, 99, 87, 16, 35, 88, 205, 229, 37, 100, 100, 128, 92, 168, 245, 150, 97, 117, 26, 214, 164, 117, 167, 236, 120, 203, 115, 25, 195, 187, 197, 68, 225, 187, 17, 133, 170, 174, 183, 81, 186, 11, 57, 35, 36, 110, 51, 244, 10, 79, 244, 120, 11, 116, 83, 98, 162, 24, 209, 105, 71, 77, 114, 8, 169, 247, 87, 114, 40, 48, 138, 242, 15, 141, 36, 3, 162, 116, 119, 207, 83, 207, 241, 51, 162, 71, 213, 194, 178, 152, 191, 33, 186, 198, 220, 68, 100, 158, 99, 175, 206, 245, 74, 211, 160, 122, 116, 228, 71, 179, 107, 172, 202, 41, 94, 5, 51, 104, 239, 124, 20, 110, 28, 40, 237, 179, 27, 55, 119, 201, 65, 167, 194, 125, 197, 89, 67, 123, 15, 248, 60, 41, 16, 248, 39, 239, 36, 77, 119, 38, 175, 43, 151, 8, 101, 75, 207, 177, 57, 162, 104, 68, 198, 244, 183, 156, 217, 228, 116, 112, 101, 167, 117, 150, 185, 39, 249, 108, 189, 188, 114, 103, 172, 158, 50, 172, 57, 111, 108, 76, 231, 57, 170, 10, 166, 15, 204, 172, 23, 142, 218, 177, 224, 70, 136, 254, 113, 167, 66, 1, 169, 155, 198, 75, 85, 248, 203, 137, 225, 21, 69, 229, 39, 90, 244, 134, 38, 236, 102, 117, 32, 186, 138, 124, 136, 194, 48, 126, 121, 52, 85, 239, 135, 128, 250, 225, 100, 18, 234, 89, 233, 36, 6, 245, 149, 144, 45, 194, 28, 97, 43, 20, 40, 1, 207, 49, 171, 192, 71, 92, 123, 222, 119, 45, 81, 197, 90, 45, 252, 101, 27, 197, 171, 65, 67, 2, 40, 19, 204, 76, 140, 198, 245, 44, 141, 39, 227, 205, 120, 81, 132, 154, 140, 67, 67, 240, 199, 237, 108, 108, 185, 247, 101, 197, 4, 126, 85, 188, 72, 170, 213, 185, 50, 18, 130, 135, 247, 9, 57, 219, 117, 58, 97, 192, 215, 219, 24, 48, 194, 86, 40, 140, 28, 133, 204, 155, 188, 229, 131, 102, 218, 197, 40, 200, 147, 214, 27, 105, 133, 11, 203, 130, 117, 142, 131, 105, 54, 225, 248, 22, 56, 97, 88, 100, 130, 202, 211, 91, 77, 177, 67, 127, 173, 105, 128, 182, 98, 128, 177, 101, 45, 114, 245, 36, 7, 168, 1, 95, 133, 194, 158, 117, 178, 21, 137, 51, 164, 245, 136, 125, 179, 32, 181, 131, 205, 203, 202, 39, 78, 172, 33, 248, 112, 40, 121, 184, 188, 74, 251, 98, 186, 5, 109, 20, 101, 18, 146, 21, 84, 199, 101, 70, 77, 76, 150, 189, 60, 154, 151, 32, 24, 124, 51, 57, 208, 89, 59, 196, 187, 165, 145, 85, 70, 159, 246, 184, 104, 138, 95, 197, 250, 107, 70, 212, 7, 6, 104, 189, 22, 140, 95, 121, 100, 146, 118, 9, 64, 153, 153, 39, 98, 130, 54, 178, 65, 41, 104, 67, 141, 44, 188, 42, 151, 138, 188, 9, 115, 32, 41, 27, 14, 215, 228, 99, 30, 201, 187, 226, 138, 198, 58, 182, 134, 14, 151, 106, 193, 85, 42, 252, 67, 137, 125, 105, 55, 160, 146, 67, 45, 196, 129, 169, 20, 195, 161, 39, 60, 67, 0, 158, 142, 197, 35, 203, 147, 206, 217, 137, 143, 144, 144, 83, 64, 186, 110, 1, 188, 87, 45, 3, 168, 147, 250, 33, 196, 52, 45, 136, 220, 62, 119, 179, 59, 31, 192, 99, 220, 219, 104, 157, 140, 79, 252, 132, 12, 232, 121, 207, 179, 71, 19, 133, 81, 44, 199, 199, 89, 19, 131, 177, 250, 200, 152, 50, 187, 28, 179, 50, 78, 109, 40, 104, 145, 136, 68, 203, 32, 202, 77, 248, 18, 180, 40, 36, 25, 42, 67, 56, 4, 48, 163, 122, 127, 58, 196, 30, 208, 100, 207, 224, 85, 21, 123, 145, 181, 192, 16, 143, 183, 166, 19, 160, 17, 42, 244, 214, 78, 72, 248, 163, 69, 50, 138, 153, 183, 191, 12, 145, 203, 3, 114, 21, 176, 23, 122, 212, 194, 99, 230, 215, 143, 89, 88, 200, 72, 21, 142, 79, 210, 17, 124, 36, 142, 12, 171, 60, 249, 140, 30, 39, 134, 136, 56, 248, 66, 139, 160, 86, 45, 246, 182, 30, 135, 54, 226, 184, 178, 102, 96, 121, 24, 224, 206, 44, 227, 175, 103, 200, 31, 165, 194, 164, 210, 188, 142, 135, 24, 37, 183, 2, 179, 190, 202, 57, 123, 51, 165, 24, 218, 200, 177, 57, 61, 73, 74, 144, 144, 12, 1, 181, 89, 37, 133, 124, 191, 128, 39, 5, 29, 54, 164, 241, 65, 164, 210, 220, 68, 3, 65, 48, 94, 3, 60, 181, 10, 7, 75, 228, 89, 199, 106, 51, 154, 154, 82, 196, 71, 54, 13, 234, 42, 86, 153, 204, 234, 104, 58, 66, 67, 10, 166, 252, 149, 69, 199, 92, 4, 146, 100, 26, 247, 164, 233, 38, 123, 204, 56, 78, 85, 113, 76, 244, 151, 65, 235, 163, 182, 157, 100, 23, 165, 85, 71, 93, 60, 2, 17, 18, 184, 179, 88, 140, 99, 116, 123, 92, 226, 204, 254, 233, 18, 151, 250, 65, 159, 156, 66, 152, 151, 143, 255, 8, 112, 216, 168, 85, 180, 136, 153, 202, 155, 180, 125, 131, 109, 98, 210, 3, 140, 195, 129, 111, 58, 105, 139, 179, 187, 255, 120, 199, 160, 21, 176, 234, 25, 96, 99, 66, 146, 225, 213, 11, 3, 225, 4, 58, 152, 202, 155, 39, 6, 62, 102, 139, 5, 226, 193, 125, 105, 45, 56, 43, 24, 19, 101, 168, 24, 81, 142, 199, 71, 114, 3, 55, 202, 24, 104, 89, 106, 244, 42, 144, 250, 177, 135, 3, 115, 215, 75, 143, 109, 66, 172, 134, 177, 139, 202, 58, 176, 71, 100, 104, 12, 242, 6, 12, 82, 154, 190, 91, 142, 244, 71, 77, 200, 204, 71, 168, 3, 62, 189, 136, 77, 62, 10, 15, 26, 148, 66, 13, 138, 60, 145, 98, 183, 175, 135, 162, 200, 163, 148, 100, 116, 52, 244, 163, 188, 43, 71, 120, 19, 172, 130, 207, 56, 113, 133, 181, 135, 247, 246, 137, 56, 236, 205, 221, 105, 52, 209, 67, 186, 23, 187, 74, 113, 37, 102, 210, 165, 90, 173, 219, 51, 35, 113, 54, 103, 64, 27, 74, 32, 184, 108, 35, 191, 16, 118, 67, 156, 182, 184, 193, 21, 56, 157, 186, 78, 111, 12, 145, 91, 231, 96, 43, 151, 3, 181, 53, 7, 14, 74, 92, 86, 73, 165, 215, 8, 5, 21, 2, 103, 6, 178, 96, 69, 117, 203, 208, 104, 127, 39, 41, 169, 42, 153, 125, 33, 234, 114, 0, 196, 30, 216, 49, 82, 117, 244, 199, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141];
-
-        // /// 768
-        // let pk = [56, 212, 133, 30, 92, 1, 13, 163, 154, 116, 112, 188, 28, 128, 145, 111, 120, 199, 189, 88, 145, 220, 211, 177, 234, 132, 182, 240, 81, 179, 70, 184, 3, 193, 185, 124, 148, 96, 64, 32, 183, 39, 155, 39, 131, 108, 48, 73, 234, 11, 154, 55, 88, 81, 11, 117, 89, 89, 50, 55, 173, 231, 37, 135, 70, 34, 6, 183, 9, 243, 101, 132, 140, 150, 85, 147, 38, 165, 254, 108, 111, 76, 245, 49, 253, 26, 24, 71, 114, 103, 246, 107, 161, 74, 242, 1, 99, 196, 31, 17, 56, 160, 25, 149, 20, 127, 39, 29, 223, 194, 190, 83, 97, 178, 129, 230, 2, 157, 33, 5, 132, 178, 133, 155, 115, 131, 102, 114, 132, 247, 103, 187, 50, 120, 43, 170, 209, 9, 51, 218, 1, 56, 163, 160, 102, 10, 20, 149, 49, 192, 63, 156, 143, 252, 203, 51, 227, 195, 165, 167, 152, 78, 33, 250, 178, 106, 167, 42, 138, 107, 148, 47, 38, 94, 82, 85, 26, 156, 128, 14, 90, 68, 128, 95, 12, 1, 65, 160, 85, 84, 33, 51, 135, 241, 5, 223, 86, 69, 132, 150, 189, 143, 70, 144, 81, 136, 109, 162, 35, 203, 159, 231, 142, 123, 57, 11, 249, 75, 10, 147, 118, 145, 175, 149, 80, 8, 43, 118, 208, 69, 203, 77, 41, 194, 60, 103, 148, 38, 8, 208, 120, 161, 200, 15, 36, 118, 122, 148, 93, 25, 240, 119, 216, 44, 155, 155, 25, 112, 115, 70, 74, 190, 105, 207, 124, 86, 38, 23, 115, 8, 243, 132, 103, 45, 82, 99, 176, 196, 130, 109, 180, 71, 14, 26, 112, 228, 117, 30, 57, 24, 171, 232, 252, 188, 59, 192, 83, 26, 232, 158, 85, 18, 33, 75, 92, 201, 74, 22, 160, 20, 188, 179, 130, 108, 121, 251, 244, 173, 208, 130, 94, 238, 251, 171, 136, 203, 124, 255, 55, 187, 141, 73, 31, 141, 233, 2, 87, 138, 30, 150, 22, 85, 86, 91, 119, 24, 120, 42, 35, 80, 79, 220, 19, 199, 131, 241, 48, 225, 119, 146, 94, 48, 93, 31, 188, 99, 204, 140, 21, 194, 198, 127, 133, 80, 12, 202, 120, 93, 233, 244, 128, 73, 5, 88, 239, 113, 170, 240, 251, 91, 81, 57, 20, 64, 18, 105, 179, 9, 196, 197, 156, 100, 210, 167, 87, 216, 133, 95, 88, 70, 86, 21, 146, 95, 30, 166, 129, 44, 177, 67, 255, 243, 131, 225, 4, 142, 40, 81, 24, 191, 147, 41, 68, 184, 111, 189, 244, 177, 185, 230, 86, 133, 102, 74, 7, 119, 92, 70, 149, 42, 170, 218, 17, 104, 245, 75, 71, 199, 162, 49, 231, 53, 92, 100, 99, 116, 103, 181, 163, 192, 156, 171, 103, 187, 53, 245, 134, 64, 194, 114, 98, 131, 187, 99, 83, 10, 21, 246, 110, 202, 72, 168, 64, 192, 12, 168, 134, 46, 40, 60, 115, 191, 187, 65, 58, 41, 21, 184, 209, 21, 154, 4, 63, 18, 197, 155, 250, 130, 130, 72, 36, 155, 118, 16, 111, 170, 97, 161, 39, 160, 40, 12, 88, 99, 80, 231, 164, 44, 183, 76, 164, 156, 171, 214, 6, 137, 30, 199, 203, 142, 132, 175, 254, 75, 46, 20, 199, 22, 88, 51, 43, 117, 86, 17, 186, 183, 151, 127, 167, 108, 231, 54, 178, 30, 211, 74, 23, 172, 14, 195, 86, 28, 169, 178, 130, 212, 162, 188, 64, 118, 151, 146, 75, 28, 249, 24, 186, 131, 211, 164, 253, 200, 37, 100, 201, 91, 217, 4, 189, 238, 233, 30, 214, 204, 179, 107, 170, 136, 160, 92, 128, 113, 41, 1, 191, 40, 10, 238, 101, 56, 236, 32, 120, 194, 168, 78, 229, 134, 47, 193, 55, 205, 146, 233, 121, 104, 214, 159, 195, 69, 58, 30, 28, 177, 97, 197, 12, 159, 36, 115, 160, 208, 144, 55, 177, 136, 160, 250, 1, 239, 195, 68, 194, 172, 143, 232, 89, 43, 10, 88, 69, 102, 98, 169, 80, 51, 101, 154, 21, 138, 45, 144, 166, 229, 12, 37, 58, 135, 151, 87, 133, 206, 41, 196, 87, 0, 0, 161, 84, 212, 179, 178, 198, 66, 32, 92, 140, 124, 249, 172, 107, 16, 113, 251, 179, 104, 171, 149, 10, 116, 75, 136, 201, 91, 165, 36, 48, 23, 131, 17, 32, 169, 4, 131, 56, 210, 152, 71, 131, 13, 18, 169, 51, 160, 154, 189, 33, 164, 107, 130, 140, 177, 78, 128, 140, 211, 81, 41, 201, 220, 110, 91, 147, 29, 74, 18, 111, 239, 224, 121, 9, 97, 142, 43, 69, 134, 231, 182, 180, 36, 150, 59, 115, 35, 186, 80, 91, 161, 18, 187, 155, 131, 74, 125, 27, 120, 173, 13, 245, 61, 85, 106, 28, 105, 54, 159, 9, 20, 139, 29, 201, 147, 141, 245, 146, 35, 240, 135, 253, 104, 51, 190, 91, 43, 194, 101, 31, 229, 137, 17, 172, 1, 70, 127, 146, 151, 223, 220, 34, 180, 26, 15, 23, 2, 113, 135, 16, 183, 140, 243, 91, 24, 101, 129, 58, 137, 109, 69, 33, 77, 51, 129, 85, 182, 192, 67, 197, 50, 51, 12, 0, 45, 82, 7, 57, 70, 122, 80, 74, 134, 102, 55, 251, 52, 81, 200, 73, 248, 248, 62, 106, 148, 20, 127, 22, 141, 165, 58, 205, 249, 216, 175, 253, 150, 138, 132, 18, 74, 154, 188, 9, 175, 150, 12, 211, 178, 159, 35, 68, 131, 27, 180, 30, 103, 96, 94, 235, 240, 13, 242, 2, 133, 113, 23, 57, 157, 215, 72, 182, 81, 74, 237, 97, 187, 47, 108, 184, 65, 209, 104, 213, 243, 94, 32, 5, 69, 115, 163, 49, 205, 72, 130, 160, 75, 7, 44, 23, 145, 88, 130, 91, 207, 71, 18, 102, 218, 13, 204, 234, 177, 160, 33, 199, 50, 84, 117, 29, 90, 22, 28, 26, 146, 6, 44, 34, 10, 33, 122, 105, 217, 130, 51, 20, 180, 222, 153, 111, 232, 212, 95, 109, 181, 175, 22, 193, 86, 20, 149, 164, 196, 48, 144, 188, 57, 76, 148, 225, 176, 236, 115, 142, 181, 98, 103, 32, 28, 46, 205, 28, 123, 73, 147, 192, 239, 192, 40, 75, 220, 154, 9, 28, 41, 79, 149, 112, 58, 113, 120, 130, 44, 138, 149, 183, 155, 30, 69, 145, 224, 153, 141, 137, 56, 117, 193, 168, 121, 192, 138, 7, 60, 198, 125, 244, 38, 187, 167, 146, 193, 138, 230, 193, 254, 186, 135, 155, 236, 84, 129, 44, 42, 255, 160, 18, 151, 59, 112, 10, 212, 142, 39, 16, 120, 40, 8, 100, 38, 134, 0, 167, 170, 48, 158, 170, 16, 152, 117, 10, 15, 138, 82, 46, 185, 41, 87, 123, 65, 47, 120, 85, 97, 54, 136, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141];
-
-        // /// 512
-        // let pk = [95, 196, 75, 153, 215, 88, 79, 56, 205, 40, 54, 12, 197, 98, 90, 144, 91, 150, 175, 18, 147, 14, 213, 181, 254, 42, 130, 252, 90, 167, 220, 75, 130, 159, 227, 118, 53, 241, 63, 90, 242, 166, 211, 8, 29, 173, 135, 135, 133, 105, 138, 10, 169, 20, 55, 76, 78, 67, 184, 159, 9, 74, 120, 146, 170, 20, 154, 56, 180, 156, 6, 160, 104, 216, 41, 168, 210, 73, 231, 83, 163, 117, 208, 151, 160, 241, 98, 230, 195, 164, 223, 232, 199, 151, 97, 65, 12, 96, 94, 211, 137, 154, 63, 196, 67, 120, 225, 79, 40, 135, 158, 143, 20, 128, 119, 230, 188, 59, 178, 174, 86, 23, 140, 73, 22, 17, 191, 106, 175, 95, 154, 156, 185, 181, 101, 146, 35, 0, 121, 64, 188, 214, 248, 162, 50, 128, 165, 96, 21, 51, 14, 133, 119, 37, 149, 135, 177, 38, 6, 244, 201, 55, 234, 19, 96, 108, 179, 187, 4, 96, 102, 173, 41, 66, 97, 226, 178, 32, 34, 188, 199, 70, 120, 165, 82, 5, 112, 216, 142, 76, 235, 66, 105, 38, 49, 231, 227, 113, 28, 75, 47, 213, 52, 127, 3, 40, 89, 131, 64, 203, 60, 101, 200, 245, 90, 192, 39, 22, 131, 16, 148, 203, 110, 185, 15, 23, 91, 23, 61, 156, 101, 3, 41, 170, 245, 19, 99, 54, 51, 187, 44, 230, 133, 142, 116, 71, 171, 196, 27, 111, 176, 109, 168, 120, 37, 114, 195, 50, 176, 150, 96, 54, 105, 38, 191, 82, 158, 216, 202, 170, 98, 67, 204, 219, 21, 43, 54, 186, 110, 71, 199, 20, 20, 92, 134, 245, 179, 182, 29, 232, 78, 241, 71, 13, 3, 250, 1, 53, 227, 81, 148, 250, 31, 179, 188, 134, 15, 165, 0, 209, 41, 154, 238, 136, 206, 86, 5, 67, 118, 193, 25, 156, 85, 61, 217, 10, 141, 111, 156, 199, 99, 200, 17, 208, 198, 109, 166, 248, 81, 171, 241, 5, 102, 53, 163, 74, 104, 170, 120, 21, 134, 143, 21, 58, 58, 92, 119, 252, 200, 177, 235, 24, 7, 251, 246, 42, 111, 180, 59, 53, 87, 0, 231, 130, 48, 148, 58, 43, 161, 225, 27, 24, 19, 69, 177, 27, 77, 70, 38, 110, 123, 53, 159, 7, 74, 80, 12, 136, 87, 215, 155, 166, 15, 100, 38, 45, 102, 44, 205, 156, 132, 137, 164, 193, 157, 246, 116, 55, 219, 25, 63, 149, 185, 118, 81, 129, 217, 21, 34, 98, 177, 22, 111, 151, 190, 83, 73, 127, 0, 28, 177, 190, 121, 2, 77, 106, 34, 137, 188, 199, 4, 225, 177, 216, 33, 1, 83, 102, 163, 204, 138, 72, 78, 107, 194, 225, 241, 184, 137, 241, 147, 35, 227, 16, 26, 160, 154, 217, 234, 98, 186, 64, 5, 3, 155, 191, 181, 153, 128, 85, 249, 63, 191, 119, 177, 68, 51, 17, 109, 89, 88, 66, 38, 84, 218, 218, 17, 39, 33, 63, 2, 183, 135, 23, 165, 160, 69, 66, 113, 213, 176, 192, 37, 23, 166, 194, 122, 60, 54, 16, 16, 29, 117, 60, 9, 162, 85, 113, 119, 84, 119, 220, 19, 178, 228, 4, 219, 73, 101, 185, 169, 53, 3, 48, 199, 58, 138, 54, 66, 211, 154, 248, 162, 56, 57, 171, 133, 198, 53, 91, 18, 242, 121, 248, 73, 129, 60, 40, 13, 84, 197, 145, 62, 153, 182, 148, 106, 10, 175, 1, 44, 140, 171, 2, 83, 150, 178, 85, 240, 2, 216, 55, 199, 97, 212, 42, 74, 235, 56, 197, 244, 86, 170, 247, 158, 22, 39, 0, 198, 180, 4, 142, 202, 111, 154, 115, 103, 249, 2, 56, 214, 123, 207, 142, 106, 13, 138, 85, 60, 7, 21, 34, 249, 210, 57, 78, 40, 72, 61, 32, 72, 190, 42, 143, 156, 140, 142, 57, 153, 26, 65, 39, 60, 126, 172, 174, 252, 106, 48, 139, 232, 112, 180, 91, 65, 23, 100, 18, 149, 74, 26, 15, 216, 61, 54, 42, 90, 178, 136, 102, 61, 236, 84, 86, 182, 40, 109, 11, 44, 236, 176, 25, 34, 251, 61, 71, 56, 2, 234, 43, 134, 99, 155, 206, 2, 69, 3, 57, 38, 28, 255, 177, 20, 225, 231, 37, 233, 6, 119, 130, 106, 22, 136, 246, 134, 178, 154, 120, 119, 156, 152, 34, 49, 93, 175, 197, 87, 83, 233, 140, 142, 211, 34, 31, 43, 50, 32, 128, 92, 138, 40, 152, 51, 85, 32, 125, 163, 111, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141];
-
-        // let mut ct = [0u8; KYBER_CIPHERTEXTBYTES];
-        // let mut ss = [0u8; KYBER_SSBYTES];
-        
-        // let encap_buf = Some([255u8; 32].as_slice());
-        // let inst0 = syst.cvr.read();
-        // crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng, encap_buf);
-        // let inst1 = syst.cvr.read();
-
-        // /// 1024
-        let sk = [70, 35, 150, 246, 178, 31, 246, 37, 185, 107, 75, 31, 92, 58, 56, 179, 200, 7, 8, 243, 59, 204, 23, 132, 190, 219, 72, 190, 224, 37, 59, 66, 108, 251, 149, 97, 135, 18, 206, 152, 37, 165, 59, 92, 200, 124, 129, 67, 175, 196, 107, 64, 98, 123, 76, 75, 189, 46, 56, 44, 236, 69, 126, 72, 167, 19, 12, 132, 18, 194, 96, 92, 71, 89, 21, 191, 8, 71, 109, 228, 184, 170, 112, 41, 138, 185, 157, 239, 147, 134, 193, 241, 18, 83, 195, 152, 198, 208, 173, 249, 217, 126, 34, 100, 160, 38, 228, 2, 17, 181, 154, 38, 162, 63, 22, 16, 42, 34, 10, 111, 43, 242, 122, 20, 1, 95, 111, 50, 97, 226, 116, 117, 140, 204, 183, 87, 164, 6, 0, 83, 23, 160, 80, 194, 45, 57, 159, 153, 73, 116, 170, 115, 19, 237, 251, 186, 70, 8, 55, 22, 19, 152, 84, 129, 176, 174, 200, 158, 221, 96, 2, 117, 75, 24, 226, 117, 185, 122, 248, 180, 173, 198, 130, 181, 115, 185, 165, 104, 115, 36, 35, 178, 13, 244, 24, 218, 10, 15, 105, 8, 102, 59, 233, 131, 62, 218, 18, 105, 18, 119, 2, 233, 125, 184, 43, 177, 29, 226, 44, 192, 118, 94, 38, 202, 182, 13, 37, 108, 187, 39, 34, 19, 21, 194, 193, 215, 95, 86, 230, 170, 76, 8, 204, 131, 69, 161, 107, 104, 0, 227, 48, 117, 108, 236, 2, 103, 192, 45, 29, 225, 202, 60, 168, 115, 129, 86, 200, 142, 214, 174, 144, 248, 66, 155, 90, 173, 140, 90, 83, 254, 38, 135, 210, 7, 79, 219, 24, 177, 36, 26, 72, 158, 133, 80, 171, 170, 123, 85, 154, 204, 106, 180, 181, 57, 108, 130, 27, 133, 67, 5, 202, 166, 106, 71, 128, 57, 204, 44, 247, 54, 95, 86, 163, 68, 209, 240, 154, 167, 58, 102, 122, 21, 206, 113, 178, 16, 217, 185, 48, 64, 58, 56, 162, 161, 12, 222, 243, 155, 158, 137, 96, 173, 70, 51, 19, 183, 171, 61, 114, 58, 113, 92, 29, 114, 155, 121, 180, 107, 123, 206, 219, 49, 185, 43, 119, 134, 74, 201, 20, 108, 21, 168, 155, 175, 225, 96, 31, 204, 131, 40, 230, 87, 166, 235, 186, 190, 248, 17, 89, 134, 220, 135, 241, 114, 52, 186, 163, 136, 94, 51, 161, 133, 104, 139, 145, 88, 131, 236, 72, 9, 197, 201, 105, 27, 149, 160, 129, 121, 164, 133, 119, 193, 114, 74, 161, 188, 246, 100, 213, 234, 73, 198, 154, 114, 124, 218, 62, 49, 231, 30, 103, 37, 126, 101, 232, 71, 223, 89, 71, 171, 226, 150, 117, 115, 172, 45, 97, 89, 249, 102, 153, 174, 22, 165, 95, 101, 78, 143, 224, 11, 234, 103, 145, 19, 90, 160, 69, 216, 81, 208, 215, 60, 209, 89, 190, 92, 0, 4, 27, 16, 51, 175, 24, 80, 12, 198, 43, 141, 198, 54, 190, 215, 14, 77, 212, 152, 30, 55, 136, 32, 235, 115, 167, 211, 188, 105, 164, 45, 203, 145, 3, 236, 139, 184, 2, 7, 99, 186, 118, 180, 130, 17, 128, 101, 41, 85, 61, 166, 72, 117, 57, 137, 71, 34, 68, 54, 114, 188, 126, 195, 22, 70, 21, 140, 166, 99, 67, 226, 230, 155, 214, 56, 204, 74, 60, 113, 30, 55, 117, 206, 136, 180, 150, 138, 44, 219, 183, 114, 160, 217, 175, 106, 163, 145, 104, 85, 0, 116, 166, 88, 45, 161, 147, 102, 246, 27, 225, 116, 3, 68, 114, 24, 45, 144, 12, 5, 152, 54, 154, 108, 101, 109, 48, 201, 149, 87, 170, 246, 228, 48, 35, 244, 23, 188, 74, 45, 142, 41, 68, 196, 230, 1, 1, 82, 191, 40, 235, 33, 25, 169, 4, 103, 170, 24, 87, 6, 57, 111, 147, 55, 34, 241, 147, 115, 211, 67, 27, 1, 123, 121, 74, 25, 185, 139, 57, 144, 24, 139, 204, 113, 66, 205, 35, 167, 90, 40, 169, 93, 44, 37, 170, 247, 194, 223, 216, 186, 6, 228, 112, 102, 148, 12, 31, 165, 86, 85, 218, 185, 190, 182, 134, 66, 140, 114, 233, 0, 112, 248, 26, 107, 251, 99, 69, 118, 97, 81, 255, 192, 89, 234, 100, 37, 95, 178, 74, 163, 228, 124, 252, 1, 48, 15, 67, 145, 26, 227, 147, 230, 215, 168, 158, 28, 34, 143, 59, 85, 215, 11, 157, 157, 75, 92, 192, 6, 113, 173, 11, 105, 72, 103, 74, 41, 105, 82, 255, 87, 101, 163, 117, 44, 147, 200, 126, 230, 21, 198, 131, 227, 161, 247, 145, 77, 44, 114, 83, 78, 107, 178, 49, 187, 16, 22, 234, 96, 231, 165, 184, 151, 227, 46, 77, 98, 163, 131, 148, 95, 217, 145, 54, 191, 231, 207, 20, 72, 73, 236, 170, 74, 5, 71, 192, 64, 153, 154, 85, 242, 73, 117, 39, 197, 114, 235, 203, 47, 236, 70, 235, 41, 65, 7, 244, 191, 100, 121, 107, 228, 188, 153, 188, 107, 134, 178, 152, 96, 81, 87, 69, 245, 180, 4, 236, 121, 9, 59, 179, 111, 199, 187, 173, 121, 182, 192, 200, 163, 186, 78, 219, 86, 164, 200, 143, 249, 71, 139, 116, 231, 201, 11, 44, 37, 105, 8, 130, 172, 132, 81, 141, 81, 17, 255, 163, 111, 241, 228, 155, 91, 188, 90, 244, 229, 123, 194, 2, 175, 18, 92, 102, 8, 42, 48, 77, 218, 134, 237, 182, 6, 182, 67, 115, 171, 182, 104, 59, 86, 148, 150, 97, 171, 96, 192, 17, 225, 76, 43, 35, 200, 146, 53, 135, 169, 116, 204, 121, 87, 76, 130, 149, 98, 16, 145, 128, 29, 55, 35, 151, 87, 133, 88, 49, 105, 195, 88, 209, 130, 89, 108, 52, 75, 34, 150, 74, 64, 149, 139, 34, 114, 186, 251, 15, 253, 43, 204, 228, 177, 79, 2, 16, 203, 200, 20, 71, 111, 64, 71, 244, 128, 86, 131, 150, 116, 43, 1, 35, 50, 161, 161, 25, 16, 86, 223, 185, 180, 58, 117, 123, 166, 172, 55, 188, 102, 98, 228, 51, 202, 241, 89, 131, 149, 230, 79, 169, 167, 47, 59, 113, 0, 106, 235, 142, 179, 115, 118, 253, 214, 23, 74, 224, 96, 90, 107, 83, 42, 182, 20, 240, 38, 65, 174, 86, 59, 242, 48, 25, 0, 244, 1, 237, 211, 180, 49, 211, 144, 169, 234, 74, 43, 54, 155, 207, 244, 184, 56, 232, 74, 136, 121, 145, 161, 103, 200, 104, 204, 155, 161, 107, 60, 49, 137, 179, 225, 144, 143, 115, 100, 96, 79, 56, 42, 42, 10, 133, 200, 227, 65, 152, 27, 159, 236, 71, 51, 48, 42, 30, 219, 68, 113, 158, 59, 83, 123, 119, 11, 35, 133, 122, 104, 243, 63, 145, 188, 128, 49, 28, 147, 42, 0, 89, 0, 251, 108, 225, 211, 167, 58, 51, 149, 224, 224, 61, 84, 220, 37, 166, 55, 182, 247, 116, 9, 181, 193, 120, 76, 130, 10, 21, 170, 101, 179, 81, 198, 27, 4, 160, 99, 204, 203, 116, 89, 77, 109, 7, 155, 130, 194, 188, 64, 84, 1, 236, 114, 191, 94, 57, 157, 32, 9, 50, 134, 140, 34, 180, 201, 27, 155, 135, 127, 29, 118, 129, 97, 42, 6, 142, 139, 121, 208, 210, 44, 66, 38, 164, 237, 225, 107, 90, 80, 105, 135, 180, 120, 26, 36, 68, 252, 117, 194, 132, 90, 172, 23, 87, 99, 129, 69, 50, 35, 86, 3, 137, 67, 162, 0, 171, 130, 228, 23, 163, 84, 234, 167, 222, 249, 80, 69, 43, 103, 206, 232, 51, 253, 103, 22, 251, 166, 145, 192, 38, 197, 223, 101, 76, 33, 244, 23, 160, 162, 75, 190, 74, 74, 148, 195, 198, 70, 213, 192, 209, 168, 163, 216, 91, 39, 73, 34, 42, 254, 7, 11, 15, 1, 92, 148, 137, 27, 217, 25, 182, 88, 241, 33, 117, 85, 204, 215, 114, 157, 146, 11, 89, 63, 246, 49, 202, 192, 126, 45, 233, 83, 205, 214, 190, 160, 97, 22, 88, 147, 162, 253, 184, 121, 25, 10, 8, 52, 60, 150, 166, 204, 202, 118, 38, 73, 56, 210, 43, 39, 22, 72, 2, 97, 207, 128, 155, 129, 168, 3, 151, 104, 179, 204, 177, 249, 16, 250, 92, 94, 3, 178, 92, 235, 123, 65, 143, 91, 163, 155, 140, 113, 62, 99, 146, 4, 91, 88, 225, 201, 45, 115, 164, 78, 225, 128, 40, 46, 178, 180, 116, 211, 70, 76, 244, 170, 72, 35, 175, 203, 40, 94, 74, 1, 139, 168, 167, 196, 118, 151, 13, 160, 226, 157, 229, 246, 153, 240, 104, 70, 93, 106, 54, 208, 18, 53, 31, 70, 153, 90, 124, 48, 58, 58, 174, 38, 167, 175, 189, 153, 98, 237, 96, 169, 221, 233, 91, 75, 248, 172, 122, 71, 73, 60, 246, 30, 227, 242, 155, 160, 70, 206, 21, 161, 118, 63, 12, 33, 250, 186, 36, 103, 0, 173, 84, 9, 176, 173, 220, 204, 151, 121, 164, 209, 252, 69, 236, 38, 31, 4, 139, 156, 157, 170, 56, 201, 236, 34, 139, 101, 5, 232, 144, 82, 38, 179, 132, 134, 128, 32, 89, 194, 197, 200, 150, 1, 86, 6, 52, 203, 19, 55, 177, 49, 83, 101, 20, 72, 66, 188, 64, 90, 41, 46, 104, 60, 175, 164, 81, 69, 38, 148, 92, 77, 251, 104, 238, 42, 205, 184, 183, 149, 50, 131, 102, 150, 213, 49, 37, 160, 69, 189, 187, 138, 59, 204, 129, 35, 8, 61, 30, 104, 44, 91, 215, 130, 12, 118, 196, 72, 53, 17, 81, 71, 79, 105, 214, 1, 215, 112, 141, 187, 44, 151, 157, 119, 82, 116, 148, 182, 133, 32, 168, 255, 102, 195, 65, 98, 202, 42, 236, 128, 114, 162, 165, 31, 242, 89, 56, 150, 72, 231, 91, 149, 193, 106, 190, 20, 96, 78, 219, 250, 186, 244, 0, 183, 106, 122, 15, 7, 219, 97, 220, 225, 145, 2, 244, 59, 45, 16, 96, 116, 123, 2, 196, 66, 84, 133, 52, 31, 213, 217, 191, 250, 167, 1, 96, 97, 55, 73, 99, 185, 133, 32, 156, 107, 154, 125, 179, 249, 73, 88, 49, 29, 2, 121, 0, 163, 216, 196, 79, 132, 53, 176, 147, 162, 54, 160, 80, 159, 25, 40, 223, 119, 25, 206, 140, 78, 144, 34, 143, 77, 184, 124, 185, 232, 130, 242, 113, 33, 128, 35, 136, 69, 211, 30, 185, 6, 198, 14, 182, 62, 15, 245, 94, 132, 184, 103, 169, 27, 121, 174, 116, 160, 58, 192, 4, 115, 200, 193, 179, 230, 174, 220, 195, 127, 30, 105, 184, 225, 54, 1, 155, 219, 1, 195, 116, 161, 34, 225, 100, 195, 37, 132, 178, 251, 207, 94, 1, 58, 69, 18, 124, 137, 51, 38, 182, 134, 3, 120, 203, 85, 37, 164, 139, 82, 43, 48, 19, 43, 118, 136, 33, 75, 105, 128, 141, 209, 154, 164, 255, 3, 62, 22, 37, 32, 22, 252, 148, 121, 34, 36, 36, 57, 62, 81, 219, 113, 21, 227, 129, 20, 230, 137, 60, 220, 200, 174, 17, 23, 164, 49, 101, 72, 1, 10, 180, 98, 159, 182, 114, 20, 141, 3, 26, 108, 96, 26, 106, 74, 102, 29, 135, 45, 142, 246, 147, 117, 10, 17, 89, 88, 113, 106, 185, 38, 62, 176, 81, 99, 87, 16, 35, 88, 205, 229, 37, 100, 100, 128, 92, 168, 245, 150, 97, 117, 26, 214, 164, 117, 167, 236, 120, 203, 115, 25, 195, 187, 197, 68, 225, 187, 17, 133, 170, 174, 183, 81, 186, 11, 57, 35, 36, 110, 51, 244, 10, 79, 244, 120, 11, 116, 83, 98, 162, 24, 209, 105, 71, 77, 114, 8, 169, 247, 87, 114, 40, 48, 138, 242, 15, 141, 36, 3, 162, 116, 119, 207, 83, 207, 241, 51, 162, 71, 213, 194, 178, 152, 191, 33, 186, 198, 220, 68, 100, 158, 99, 175, 206, 245, 74, 211, 160, 122, 116, 228, 71, 179, 107, 172, 202, 41, 94, 5, 51, 104, 239, 124, 20, 110, 28, 40, 237, 179, 27, 55, 119, 201, 65, 167, 194, 125, 197, 89, 67, 123, 15, 248, 60, 41, 16, 248, 39, 239, 36, 77, 119, 38, 175, 43, 151, 8, 101, 75, 207, 177, 57, 162, 104, 68, 198, 244, 183, 156, 217, 228, 116, 112, 101, 167, 117, 150, 185, 39, 249, 108, 189, 188, 114, 103, 172, 158, 50, 172, 57, 111, 108, 76, 231, 57, 170, 10, 166, 15, 204, 172, 23, 142, 218, 177, 224, 70, 136, 254, 113, 167, 66, 1, 169, 155, 198, 75, 85, 248, 203, 137, 225, 21, 69, 229, 39, 90, 244, 134, 38, 236, 102, 117, 32, 186, 138, 124, 136, 194, 48, 126, 121, 52, 85, 239, 135, 128, 250, 225, 100, 18, 234, 89, 233, 36, 6, 245, 149, 144, 45, 194, 28, 97, 43, 20, 40, 1, 207, 49, 171, 192, 71, 92, 123, 222, 119, 45, 81, 197, 90, 45, 252, 101, 27, 197, 171, 65, 67, 2, 40, 19, 204, 76, 140, 198, 245, 44, 141, 39, 227, 205, 120, 81, 132, 154, 140, 67, 67, 240, 199, 237, 108, 108, 185, 247, 101, 197, 4, 126, 85, 188, 72, 170, 213, 185, 50, 18, 130, 135, 247, 9, 57, 219, 117, 58, 97, 192, 215, 219, 24, 48, 194, 86, 40, 140, 28, 133, 204, 155, 188, 229, 131, 102, 218, 197, 40, 200, 147, 214, 27, 105, 133, 11, 203, 130, 117, 142, 131, 105, 54, 225, 248, 22, 56, 97, 88, 100, 130, 202, 211, 91, 77, 177, 67, 127, 173, 105, 128, 182, 98, 128, 177, 101, 45, 114, 245, 36, 7, 168, 1, 95, 133, 194, 158, 117, 178, 21, 137, 51, 164, 245, 136, 125, 179, 32, 181, 131, 205, 203, 202, 39, 78, 172, 33, 248, 112, 40, 121, 184, 188, 74, 251, 98, 186, 5, 109, 20, 101, 18, 146, 21, 84, 199, 101, 70, 77, 76, 150, 189, 60, 154, 151, 32, 24, 124, 51, 57, 208, 89, 59, 196, 187, 165, 145, 85, 70, 159, 246, 184, 104, 138, 95, 197, 250, 107, 70, 212, 7, 6, 104, 189, 22, 140, 95, 121, 100, 146, 118, 9, 64, 153, 153, 39, 98, 130, 54, 178, 65, 41, 104, 67, 141, 44, 188, 42, 151, 138, 188, 9, 115, 32, 41, 27, 14, 215, 228, 99, 30, 201, 187, 226, 138, 198, 58, 182, 134, 14, 151, 106, 193, 85, 42, 252, 67, 137, 125, 105, 55, 160, 146, 67, 45, 196, 129, 169, 20, 195, 161, 39, 60, 67, 0, 158, 142, 197, 35, 203, 147, 206, 217, 137, 143, 144, 144, 83, 64, 186, 110, 1, 188, 87, 45, 3, 168, 147, 250, 33, 196, 52, 45, 136, 220, 62, 119, 179, 59, 31, 192, 99, 220, 219, 104, 157, 140, 79, 252, 132, 12, 232, 121, 207, 179, 71, 19, 133, 81, 44, 199, 199, 89, 19, 131, 177, 250, 200, 152, 50, 187, 28, 179, 50, 78, 109, 40, 104, 145, 136, 68, 203, 32, 202, 77, 248, 18, 180, 40, 36, 25, 42, 67, 56, 4, 48, 163, 122, 127, 58, 196, 30, 208, 100, 207, 224, 85, 21, 123, 145, 181, 192, 16, 143, 183, 166, 19, 160, 17, 42, 244, 214, 78, 72, 248, 163, 69, 50, 138, 153, 183, 191, 12, 145, 203, 3, 114, 21, 176, 23, 122, 212, 194, 99, 230, 215, 143, 89, 88, 200, 72, 21, 142, 79, 210, 17, 124, 36, 142, 12, 171, 60, 249, 140, 30, 39, 134, 136, 56, 248, 66, 139, 160, 86, 45, 246, 182, 30, 135, 54, 226, 184, 178, 102, 96, 121, 24, 224, 206, 44, 227, 175, 103, 200, 31, 165, 194, 164, 210, 188, 142, 135, 24, 37, 183, 2, 179, 190, 202, 57, 123, 51, 165, 24, 218, 200, 177, 57, 61, 73, 74, 144, 144, 12, 1, 181, 89, 37, 133, 124, 191, 128, 39, 5, 29, 54, 164, 241, 65, 164, 210, 220, 68, 3, 65, 48, 94, 3, 60, 181, 10, 7, 75, 228, 89, 199, 106, 51, 154, 154, 82, 196, 71, 54, 13, 234, 42, 86, 153, 204, 234, 104, 58, 66, 67, 10, 166, 252, 149, 69, 199, 92, 4, 146, 100, 26, 247, 164, 233, 38, 123, 204, 56, 78, 85, 113, 76, 244, 151, 65, 235, 163, 182, 157, 100, 23, 165, 85, 71, 93, 60, 2, 17, 18, 184, 179, 88, 140, 99, 116, 123, 92, 226, 204, 254, 233, 18, 151, 250, 65, 159, 156, 66, 152, 151, 143, 255, 8, 112, 216, 168, 85, 180, 136, 153, 202, 155, 180, 125, 131, 109, 98, 210, 3, 140, 195, 129, 111, 58, 105, 139, 179, 187, 255, 120, 199, 160, 21, 176, 234, 25, 96, 99, 66, 146, 225, 213, 11, 3, 225, 4, 58, 152, 202, 155, 39, 6, 62, 102, 139, 5, 226, 193, 125, 105, 45, 56, 43, 24, 19, 101, 168, 24, 81, 142, 199, 71, 114, 3, 55, 202, 24, 104, 89, 106, 244, 42, 144, 250, 177, 135, 3, 115, 215, 75, 143, 109, 66, 172, 134, 177, 139, 202, 58, 176, 71, 100, 104, 12, 242, 6, 12, 82, 154, 190, 91, 142, 244, 71, 77, 200, 204, 71, 168, 3, 62, 189, 136, 77, 62, 10, 15, 26, 148, 66, 13, 138, 60, 145, 98, 183, 175, 135, 162, 200, 163, 148, 100, 116, 52, 244, 163, 188, 43, 71, 120, 19, 172, 130, 207, 56, 113, 133, 181, 135, 247, 246, 137, 56, 236, 205, 221, 105, 52, 209, 67, 186, 23, 187, 74, 113, 37, 102, 210, 165, 90, 173, 219, 51, 35, 113, 54, 103, 64, 27, 74, 32, 184, 108, 35, 191, 16, 118, 67, 156, 182, 184, 193, 21, 56, 157, 186, 78, 111, 12, 145, 91, 231, 96, 43, 151, 3, 181, 53, 7, 14, 74, 92, 86, 73, 165, 215, 8, 5, 21, 2, 103, 6, 178, 96, 69, 117, 203, 208, 104, 127, 39, 41, 169, 42, 153, 125, 33, 234, 114, 0, 196, 30, 216, 49, 82, 117, 244, 199, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141, 154, 47, 83, 149, 8, 127, 17, 213, 141, 188, 29, 37, 88, 138, 225, 16, 32, 157, 170, 171, 117, 221, 149, 69, 118, 171, 64, 170, 112, 153, 121, 212, 62, 42, 46, 166, 201, 196, 118, 252, 73, 55, 176, 19, 201, 147, 167, 147, 214, 192, 171, 153, 96, 105, 91, 168, 56, 246, 73, 218, 83, 156, 163, 208];
-        let ct = [53, 97, 120, 38, 42, 136, 110, 245, 48, 201, 219, 220, 245, 126, 219, 17, 65, 107, 209, 214, 136, 202, 183, 149, 78, 13, 113, 74, 236, 151, 216, 131, 88, 154, 71, 91, 24, 100, 70, 144, 68, 98, 171, 36, 191, 247, 30, 70, 19, 110, 208, 128, 68, 95, 87, 79, 243, 228, 60, 149, 144, 203, 46, 163, 26, 183, 138, 189, 153, 135, 143, 243, 35, 23, 164, 87, 113, 52, 247, 191, 227, 87, 229, 124, 185, 221, 222, 121, 184, 155, 223, 153, 68, 166, 179, 127, 224, 185, 76, 69, 146, 32, 3, 228, 235, 189, 120, 120, 57, 232, 48, 84, 157, 252, 192, 155, 28, 132, 88, 231, 252, 155, 206, 194, 4, 196, 135, 208, 206, 10, 65, 31, 62, 253, 194, 95, 88, 46, 136, 42, 105, 223, 244, 85, 217, 27, 242, 54, 228, 0, 231, 149, 183, 35, 162, 230, 33, 213, 197, 55, 84, 47, 255, 30, 164, 128, 237, 74, 81, 156, 156, 108, 2, 222, 68, 172, 41, 241, 40, 135, 187, 154, 228, 130, 32, 54, 177, 80, 207, 212, 37, 210, 11, 213, 216, 58, 110, 139, 7, 7, 254, 54, 45, 58, 236, 244, 148, 184, 137, 38, 100, 132, 22, 126, 132, 186, 125, 114, 120, 191, 140, 146, 232, 112, 139, 9, 207, 52, 125, 206, 150, 242, 25, 93, 131, 120, 44, 242, 201, 11, 38, 169, 238, 13, 9, 101, 128, 2, 69, 84, 95, 91, 43, 149, 104, 31, 173, 74, 242, 128, 7, 220, 170, 187, 205, 16, 252, 52, 17, 183, 192, 103, 86, 34, 177, 194, 104, 150, 95, 4, 173, 8, 123, 150, 139, 115, 19, 183, 156, 46, 29, 233, 5, 248, 31, 225, 53, 124, 9, 72, 202, 204, 52, 97, 106, 251, 180, 46, 150, 189, 149, 89, 80, 194, 232, 128, 123, 4, 141, 197, 219, 59, 172, 82, 234, 206, 8, 39, 72, 14, 64, 37, 126, 238, 27, 249, 31, 53, 122, 45, 203, 186, 211, 100, 201, 79, 0, 124, 41, 113, 0, 215, 103, 111, 95, 73, 89, 56, 153, 200, 169, 160, 72, 232, 36, 235, 45, 162, 208, 128, 192, 190, 125, 155, 193, 167, 230, 104, 248, 199, 33, 108, 141, 81, 246, 143, 89, 48, 86, 194, 111, 129, 182, 216, 171, 90, 235, 74, 97, 130, 111, 251, 166, 172, 172, 241, 61, 196, 164, 249, 45, 136, 96, 80, 20, 223, 132, 216, 109, 190, 27, 237, 174, 134, 137, 168, 175, 219, 152, 115, 217, 195, 222, 184, 242, 204, 187, 243, 117, 186, 195, 116, 220, 56, 123, 241, 126, 216, 158, 84, 76, 46, 142, 121, 163, 159, 157, 20, 174, 55, 79, 62, 134, 44, 32, 159, 135, 91, 167, 20, 46, 15, 3, 22, 228, 209, 208, 224, 170, 132, 100, 168, 133, 15, 209, 150, 192, 22, 94, 20, 36, 232, 78, 255, 216, 70, 212, 103, 53, 29, 64, 234, 122, 98, 141, 113, 53, 229, 87, 14, 76, 127, 183, 104, 130, 25, 28, 166, 227, 172, 104, 102, 181, 232, 113, 43, 206, 56, 91, 84, 26, 45, 206, 15, 94, 148, 97, 62, 212, 205, 236, 60, 87, 82, 162, 20, 113, 200, 158, 216, 250, 27, 45, 242, 223, 97, 148, 112, 125, 82, 18, 65, 13, 174, 152, 207, 227, 115, 211, 191, 243, 143, 180, 20, 6, 153, 235, 157, 188, 99, 32, 89, 55, 214, 154, 246, 187, 158, 211, 150, 80, 132, 177, 131, 84, 230, 194, 65, 86, 61, 113, 1, 253, 195, 249, 161, 16, 99, 7, 175, 76, 118, 232, 10, 46, 180, 26, 252, 250, 101, 47, 245, 243, 135, 20, 226, 20, 168, 179, 44, 243, 2, 121, 144, 101, 82, 130, 14, 51, 222, 5, 114, 23, 126, 212, 44, 88, 32, 139, 226, 145, 31, 145, 169, 148, 109, 103, 58, 100, 148, 204, 32, 19, 190, 220, 72, 20, 184, 243, 63, 193, 245, 148, 237, 244, 20, 242, 139, 193, 133, 94, 163, 191, 231, 13, 106, 120, 187, 63, 153, 65, 65, 99, 222, 212, 97, 46, 23, 205, 220, 3, 32, 249, 80, 178, 87, 217, 253, 62, 202, 250, 251, 205, 242, 78, 198, 123, 114, 231, 228, 232, 170, 60, 111, 215, 231, 123, 210, 218, 38, 69, 160, 195, 229, 185, 59, 34, 30, 139, 192, 58, 23, 99, 146, 203, 52, 71, 45, 27, 115, 170, 250, 178, 85, 37, 244, 163, 76, 29, 0, 214, 209, 22, 224, 125, 160, 20, 119, 183, 44, 78, 63, 207, 142, 85, 172, 183, 99, 163, 6, 176, 89, 67, 149, 121, 115, 43, 84, 249, 131, 67, 11, 203, 184, 136, 218, 31, 174, 215, 178, 222, 100, 93, 198, 91, 162, 3, 200, 242, 49, 217, 79, 32, 24, 98, 64, 75, 8, 108, 60, 117, 15, 229, 243, 15, 102, 151, 134, 40, 221, 16, 128, 144, 111, 196, 165, 106, 14, 109, 3, 7, 250, 98, 88, 217, 50, 103, 156, 2, 230, 199, 35, 252, 196, 203, 77, 210, 222, 29, 84, 82, 244, 78, 243, 141, 107, 30, 97, 90, 224, 19, 161, 233, 131, 161, 131, 3, 189, 78, 255, 90, 201, 152, 164, 8, 146, 87, 32, 3, 200, 103, 160, 25, 236, 196, 147, 255, 157, 219, 53, 142, 47, 187, 0, 95, 119, 79, 124, 93, 189, 216, 225, 115, 27, 64, 112, 187, 91, 200, 150, 100, 48, 222, 159, 113, 239, 85, 68, 128, 109, 230, 123, 252, 125, 221, 35, 52, 126, 202, 237, 139, 245, 58, 65, 129, 1, 142, 99, 27, 95, 128, 140, 40, 167, 60, 100, 157, 68, 35, 38, 145, 234, 244, 60, 144, 94, 4, 180, 25, 38, 182, 208, 32, 188, 191, 17, 78, 140, 191, 192, 243, 238, 50, 7, 45, 71, 235, 145, 115, 174, 108, 240, 111, 12, 154, 196, 34, 57, 87, 206, 149, 207, 155, 237, 185, 206, 5, 38, 50, 233, 119, 146, 78, 30, 211, 210, 189, 188, 116, 82, 239, 151, 5, 191, 2, 169, 191, 217, 99, 56, 174, 157, 167, 115, 147, 211, 14, 253, 53, 246, 27, 220, 19, 25, 59, 235, 246, 180, 189, 201, 236, 225, 132, 109, 117, 29, 12, 20, 89, 188, 187, 185, 87, 8, 170, 96, 234, 182, 179, 58, 252, 102, 95, 237, 70, 217, 222, 123, 159, 246, 243, 230, 6, 78, 78, 191, 35, 114, 192, 27, 163, 138, 221, 101, 38, 209, 186, 74, 108, 119, 230, 101, 120, 128, 51, 136, 64, 137, 230, 98, 109, 43, 176, 126, 78, 76, 119, 247, 212, 103, 182, 157, 14, 182, 245, 219, 238, 34, 251, 245, 236, 77, 231, 16, 118, 224, 8, 183, 231, 23, 235, 249, 252, 178, 105, 222, 166, 105, 240, 220, 151, 134, 55, 38, 224, 170, 96, 148, 106, 133, 212, 13, 152, 242, 141, 3, 166, 235, 115, 59, 244, 52, 172, 127, 235, 123, 97, 146, 239, 222, 191, 23, 184, 231, 62, 145, 169, 70, 164, 205, 106, 126, 241, 40, 13, 134, 107, 103, 134, 198, 189, 152, 218, 240, 236, 105, 125, 212, 206, 49, 17, 202, 118, 161, 190, 175, 163, 119, 112, 209, 202, 210, 241, 104, 207, 182, 34, 202, 52, 44, 154, 97, 178, 124, 70, 174, 39, 202, 232, 81, 126, 236, 26, 2, 104, 66, 76, 241, 134, 171, 152, 64, 209, 169, 215, 7, 101, 54, 135, 135, 40, 241, 151, 11, 210, 208, 90, 27, 197, 83, 73, 200, 150, 33, 147, 158, 68, 62, 128, 246, 17, 199, 25, 239, 163, 148, 204, 124, 242, 50, 216, 142, 217, 45, 119, 214, 138, 19, 230, 96, 15, 103, 158, 218, 183, 103, 214, 135, 228, 91, 59, 61, 120, 252, 0, 190, 167, 56, 161, 45, 2, 202, 47, 72, 30, 209, 173, 74, 34, 182, 78, 140, 127, 185, 235, 199, 113, 133, 82, 85, 171, 178, 194, 105, 8, 207, 6, 68, 162, 170, 166, 34, 4, 110, 106, 101, 234, 170, 130, 193, 172, 180, 68, 163, 82, 212, 26, 11, 230, 51, 58, 35, 40, 191, 175, 149, 44, 230, 140, 20, 95, 25, 64, 146, 208, 13, 152, 123, 125, 240, 145, 21, 7, 39, 52, 106, 121, 47, 120, 248, 234, 6, 104, 199, 224, 12, 248, 53, 110, 199, 103, 10, 4, 59, 143, 53, 162, 164, 151, 111, 181, 221, 65, 26, 18, 130, 135, 212, 205, 240, 131, 32, 167, 145, 169, 226, 183, 82, 132, 227, 97, 20, 31, 208, 66, 58, 14, 219, 199, 68, 167, 87, 31, 144, 31, 40, 221, 41, 194, 124, 248, 113, 60, 89, 116, 62, 54, 183, 153, 203, 233, 41, 194, 151, 37, 35, 17, 181, 22, 250, 159, 146, 198, 67, 111, 14, 212, 225, 119, 146, 213, 125, 132, 190, 86, 242, 27, 40, 13, 157, 10, 88, 78, 104, 140, 234, 182, 112, 165, 37, 189, 161, 239, 211, 47, 176, 20, 18, 244, 14, 88, 176, 244, 194, 57, 135, 146, 175, 222, 51, 75, 146, 2, 4, 103, 71, 142, 103, 124, 96, 209, 29, 92, 142, 104, 14, 2, 5, 213, 131, 224, 149, 114, 130, 46, 113, 119, 49, 70, 204, 227, 196, 44, 159, 4, 115, 174, 147];
-
-        // /// 768
-        // let sk = [70, 35, 150, 246, 178, 31, 246, 37, 185, 107, 75, 31, 92, 58, 56, 179, 200, 7, 8, 243, 59, 204, 23, 132, 190, 219, 72, 190, 224, 37, 59, 66, 108, 251, 149, 97, 135, 18, 206, 152, 37, 165, 59, 92, 200, 124, 129, 67, 175, 196, 107, 64, 98, 123, 76, 75, 189, 46, 56, 44, 236, 69, 126, 72, 167, 19, 12, 132, 18, 194, 96, 92, 71, 89, 21, 191, 8, 71, 109, 228, 184, 170, 112, 41, 138, 185, 157, 239, 147, 134, 193, 241, 18, 83, 195, 152, 198, 208, 173, 249, 217, 126, 34, 100, 160, 38, 228, 2, 17, 181, 154, 38, 162, 63, 22, 16, 42, 34, 10, 111, 43, 242, 122, 20, 1, 95, 111, 50, 97, 226, 116, 117, 140, 204, 183, 87, 164, 6, 0, 83, 23, 160, 80, 194, 45, 57, 159, 153, 73, 116, 170, 115, 19, 237, 251, 186, 70, 8, 55, 22, 19, 152, 84, 129, 176, 174, 200, 158, 221, 96, 2, 117, 75, 24, 226, 117, 185, 122, 248, 180, 173, 198, 130, 181, 115, 185, 165, 104, 115, 36, 35, 178, 13, 244, 24, 218, 10, 15, 105, 8, 102, 59, 233, 131, 62, 218, 18, 105, 18, 119, 2, 233, 125, 184, 43, 177, 29, 226, 44, 192, 118, 94, 38, 202, 182, 13, 37, 108, 187, 39, 34, 19, 21, 194, 193, 215, 95, 86, 230, 170, 76, 8, 204, 131, 69, 161, 107, 104, 0, 227, 48, 117, 108, 236, 2, 103, 192, 45, 29, 225, 202, 60, 168, 115, 129, 86, 200, 142, 214, 174, 144, 248, 66, 155, 90, 173, 140, 90, 83, 254, 38, 135, 210, 7, 79, 219, 24, 177, 36, 26, 72, 158, 133, 80, 171, 170, 123, 85, 154, 204, 106, 180, 181, 57, 108, 130, 27, 133, 67, 5, 202, 166, 106, 71, 128, 57, 204, 44, 247, 54, 95, 86, 163, 68, 209, 240, 154, 167, 58, 102, 122, 21, 206, 113, 178, 16, 217, 185, 48, 64, 58, 56, 162, 161, 12, 222, 243, 155, 158, 137, 96, 173, 70, 51, 19, 183, 171, 61, 114, 58, 113, 92, 29, 114, 155, 121, 180, 107, 123, 206, 219, 49, 185, 43, 119, 134, 74, 201, 20, 108, 21, 168, 155, 175, 225, 96, 31, 204, 131, 40, 230, 87, 166, 235, 186, 190, 248, 17, 89, 134, 220, 135, 241, 114, 52, 186, 163, 136, 94, 51, 161, 133, 104, 139, 145, 88, 131, 236, 72, 9, 197, 201, 105, 27, 149, 160, 129, 121, 164, 133, 119, 193, 114, 74, 161, 188, 246, 100, 213, 234, 73, 198, 154, 114, 124, 218, 62, 49, 231, 30, 103, 37, 126, 101, 232, 71, 223, 89, 71, 171, 226, 150, 117, 115, 172, 45, 97, 89, 249, 102, 153, 174, 22, 165, 95, 101, 78, 143, 224, 11, 234, 103, 145, 19, 90, 160, 69, 216, 81, 208, 215, 60, 209, 89, 190, 92, 0, 4, 27, 16, 51, 175, 24, 80, 12, 198, 43, 141, 198, 54, 190, 215, 14, 77, 212, 152, 30, 55, 136, 32, 235, 115, 167, 211, 188, 105, 164, 45, 203, 145, 3, 236, 139, 184, 2, 7, 99, 186, 118, 180, 130, 17, 128, 101, 41, 85, 61, 166, 72, 117, 57, 137, 71, 34, 68, 54, 114, 188, 126, 195, 22, 70, 21, 140, 166, 99, 67, 226, 230, 155, 214, 56, 204, 74, 60, 113, 30, 55, 117, 206, 136, 180, 150, 138, 44, 219, 183, 114, 160, 217, 175, 106, 163, 145, 104, 85, 0, 116, 166, 88, 45, 161, 147, 102, 246, 27, 225, 116, 3, 68, 114, 24, 45, 144, 12, 5, 152, 54, 154, 108, 101, 109, 48, 201, 149, 87, 170, 246, 228, 48, 35, 244, 23, 188, 74, 45, 142, 41, 68, 196, 230, 1, 1, 82, 191, 40, 235, 33, 25, 169, 4, 103, 170, 24, 87, 6, 57, 111, 147, 55, 34, 241, 147, 115, 211, 67, 27, 1, 123, 121, 74, 25, 185, 139, 57, 144, 24, 139, 204, 113, 66, 205, 35, 167, 90, 40, 169, 93, 44, 37, 170, 247, 194, 223, 216, 186, 6, 228, 112, 102, 148, 12, 31, 165, 86, 85, 218, 185, 190, 182, 134, 66, 140, 114, 233, 0, 112, 248, 26, 107, 251, 99, 69, 118, 97, 81, 255, 192, 89, 234, 100, 37, 95, 178, 74, 163, 228, 124, 252, 1, 48, 15, 67, 145, 26, 227, 147, 230, 215, 168, 158, 28, 34, 143, 59, 85, 215, 11, 157, 157, 75, 92, 192, 6, 113, 173, 11, 105, 72, 103, 74, 41, 105, 82, 255, 87, 101, 163, 117, 44, 147, 200, 126, 230, 21, 198, 131, 227, 161, 247, 145, 77, 44, 114, 83, 78, 107, 178, 49, 187, 16, 22, 234, 96, 231, 165, 184, 151, 227, 46, 77, 98, 163, 131, 148, 95, 217, 145, 54, 191, 231, 207, 20, 72, 73, 236, 170, 74, 5, 71, 192, 64, 153, 154, 85, 242, 73, 117, 39, 197, 114, 235, 203, 47, 236, 70, 235, 41, 65, 7, 244, 191, 100, 121, 107, 228, 188, 153, 188, 107, 134, 178, 152, 96, 81, 87, 69, 245, 180, 4, 236, 121, 9, 59, 179, 111, 199, 187, 173, 121, 182, 192, 200, 163, 186, 78, 219, 86, 164, 200, 143, 249, 71, 139, 116, 231, 201, 11, 44, 37, 105, 8, 130, 172, 132, 81, 141, 81, 17, 255, 163, 111, 241, 228, 155, 91, 188, 90, 244, 229, 123, 194, 2, 175, 18, 92, 102, 8, 42, 48, 77, 218, 134, 237, 182, 6, 182, 67, 115, 171, 182, 104, 59, 86, 148, 150, 97, 171, 96, 192, 17, 225, 76, 43, 35, 200, 146, 53, 135, 169, 116, 204, 121, 87, 76, 130, 149, 98, 16, 145, 128, 29, 55, 35, 151, 87, 133, 88, 49, 105, 195, 88, 209, 130, 89, 108, 52, 75, 34, 150, 74, 64, 149, 139, 34, 114, 186, 251, 15, 253, 43, 204, 228, 177, 79, 2, 16, 203, 200, 20, 71, 111, 64, 71, 244, 128, 86, 131, 150, 116, 43, 1, 35, 50, 161, 161, 25, 16, 86, 223, 185, 180, 58, 117, 123, 166, 172, 55, 188, 102, 98, 228, 51, 202, 241, 89, 131, 149, 230, 79, 169, 167, 47, 59, 113, 0, 106, 235, 142, 179, 115, 118, 253, 214, 23, 74, 224, 96, 90, 107, 83, 42, 182, 20, 240, 38, 65, 174, 86, 59, 242, 48, 25, 0, 244, 1, 237, 211, 180, 49, 211, 144, 169, 234, 74, 43, 54, 155, 207, 244, 184, 56, 232, 74, 136, 121, 145, 161, 103, 200, 104, 204, 155, 161, 107, 60, 49, 137, 179, 225, 144, 143, 115, 100, 96, 79, 56, 42, 42, 10, 133, 200, 227, 65, 152, 27, 159, 236, 71, 51, 48, 42, 30, 219, 68, 113, 158, 59, 83, 123, 119, 11, 56, 212, 133, 30, 92, 1, 13, 163, 154, 116, 112, 188, 28, 128, 145, 111, 120, 199, 189, 88, 145, 220, 211, 177, 234, 132, 182, 240, 81, 179, 70, 184, 3, 193, 185, 124, 148, 96, 64, 32, 183, 39, 155, 39, 131, 108, 48, 73, 234, 11, 154, 55, 88, 81, 11, 117, 89, 89, 50, 55, 173, 231, 37, 135, 70, 34, 6, 183, 9, 243, 101, 132, 140, 150, 85, 147, 38, 165, 254, 108, 111, 76, 245, 49, 253, 26, 24, 71, 114, 103, 246, 107, 161, 74, 242, 1, 99, 196, 31, 17, 56, 160, 25, 149, 20, 127, 39, 29, 223, 194, 190, 83, 97, 178, 129, 230, 2, 157, 33, 5, 132, 178, 133, 155, 115, 131, 102, 114, 132, 247, 103, 187, 50, 120, 43, 170, 209, 9, 51, 218, 1, 56, 163, 160, 102, 10, 20, 149, 49, 192, 63, 156, 143, 252, 203, 51, 227, 195, 165, 167, 152, 78, 33, 250, 178, 106, 167, 42, 138, 107, 148, 47, 38, 94, 82, 85, 26, 156, 128, 14, 90, 68, 128, 95, 12, 1, 65, 160, 85, 84, 33, 51, 135, 241, 5, 223, 86, 69, 132, 150, 189, 143, 70, 144, 81, 136, 109, 162, 35, 203, 159, 231, 142, 123, 57, 11, 249, 75, 10, 147, 118, 145, 175, 149, 80, 8, 43, 118, 208, 69, 203, 77, 41, 194, 60, 103, 148, 38, 8, 208, 120, 161, 200, 15, 36, 118, 122, 148, 93, 25, 240, 119, 216, 44, 155, 155, 25, 112, 115, 70, 74, 190, 105, 207, 124, 86, 38, 23, 115, 8, 243, 132, 103, 45, 82, 99, 176, 196, 130, 109, 180, 71, 14, 26, 112, 228, 117, 30, 57, 24, 171, 232, 252, 188, 59, 192, 83, 26, 232, 158, 85, 18, 33, 75, 92, 201, 74, 22, 160, 20, 188, 179, 130, 108, 121, 251, 244, 173, 208, 130, 94, 238, 251, 171, 136, 203, 124, 255, 55, 187, 141, 73, 31, 141, 233, 2, 87, 138, 30, 150, 22, 85, 86, 91, 119, 24, 120, 42, 35, 80, 79, 220, 19, 199, 131, 241, 48, 225, 119, 146, 94, 48, 93, 31, 188, 99, 204, 140, 21, 194, 198, 127, 133, 80, 12, 202, 120, 93, 233, 244, 128, 73, 5, 88, 239, 113, 170, 240, 251, 91, 81, 57, 20, 64, 18, 105, 179, 9, 196, 197, 156, 100, 210, 167, 87, 216, 133, 95, 88, 70, 86, 21, 146, 95, 30, 166, 129, 44, 177, 67, 255, 243, 131, 225, 4, 142, 40, 81, 24, 191, 147, 41, 68, 184, 111, 189, 244, 177, 185, 230, 86, 133, 102, 74, 7, 119, 92, 70, 149, 42, 170, 218, 17, 104, 245, 75, 71, 199, 162, 49, 231, 53, 92, 100, 99, 116, 103, 181, 163, 192, 156, 171, 103, 187, 53, 245, 134, 64, 194, 114, 98, 131, 187, 99, 83, 10, 21, 246, 110, 202, 72, 168, 64, 192, 12, 168, 134, 46, 40, 60, 115, 191, 187, 65, 58, 41, 21, 184, 209, 21, 154, 4, 63, 18, 197, 155, 250, 130, 130, 72, 36, 155, 118, 16, 111, 170, 97, 161, 39, 160, 40, 12, 88, 99, 80, 231, 164, 44, 183, 76, 164, 156, 171, 214, 6, 137, 30, 199, 203, 142, 132, 175, 254, 75, 46, 20, 199, 22, 88, 51, 43, 117, 86, 17, 186, 183, 151, 127, 167, 108, 231, 54, 178, 30, 211, 74, 23, 172, 14, 195, 86, 28, 169, 178, 130, 212, 162, 188, 64, 118, 151, 146, 75, 28, 249, 24, 186, 131, 211, 164, 253, 200, 37, 100, 201, 91, 217, 4, 189, 238, 233, 30, 214, 204, 179, 107, 170, 136, 160, 92, 128, 113, 41, 1, 191, 40, 10, 238, 101, 56, 236, 32, 120, 194, 168, 78, 229, 134, 47, 193, 55, 205, 146, 233, 121, 104, 214, 159, 195, 69, 58, 30, 28, 177, 97, 197, 12, 159, 36, 115, 160, 208, 144, 55, 177, 136, 160, 250, 1, 239, 195, 68, 194, 172, 143, 232, 89, 43, 10, 88, 69, 102, 98, 169, 80, 51, 101, 154, 21, 138, 45, 144, 166, 229, 12, 37, 58, 135, 151, 87, 133, 206, 41, 196, 87, 0, 0, 161, 84, 212, 179, 178, 198, 66, 32, 92, 140, 124, 249, 172, 107, 16, 113, 251, 179, 104, 171, 149, 10, 116, 75, 136, 201, 91, 165, 36, 48, 23, 131, 17, 32, 169, 4, 131, 56, 210, 152, 71, 131, 13, 18, 169, 51, 160, 154, 189, 33, 164, 107, 130, 140, 177, 78, 128, 140, 211, 81, 41, 201, 220, 110, 91, 147, 29, 74, 18, 111, 239, 224, 121, 9, 97, 142, 43, 69, 134, 231, 182, 180, 36, 150, 59, 115, 35, 186, 80, 91, 161, 18, 187, 155, 131, 74, 125, 27, 120, 173, 13, 245, 61, 85, 106, 28, 105, 54, 159, 9, 20, 139, 29, 201, 147, 141, 245, 146, 35, 240, 135, 253, 104, 51, 190, 91, 43, 194, 101, 31, 229, 137, 17, 172, 1, 70, 127, 146, 151, 223, 220, 34, 180, 26, 15, 23, 2, 113, 135, 16, 183, 140, 243, 91, 24, 101, 129, 58, 137, 109, 69, 33, 77, 51, 129, 85, 182, 192, 67, 197, 50, 51, 12, 0, 45, 82, 7, 57, 70, 122, 80, 74, 134, 102, 55, 251, 52, 81, 200, 73, 248, 248, 62, 106, 148, 20, 127, 22, 141, 165, 58, 205, 249, 216, 175, 253, 150, 138, 132, 18, 74, 154, 188, 9, 175, 150, 12, 211, 178, 159, 35, 68, 131, 27, 180, 30, 103, 96, 94, 235, 240, 13, 242, 2, 133, 113, 23, 57, 157, 215, 72, 182, 81, 74, 237, 97, 187, 47, 108, 184, 65, 209, 104, 213, 243, 94, 32, 5, 69, 115, 163, 49, 205, 72, 130, 160, 75, 7, 44, 23, 145, 88, 130, 91, 207, 71, 18, 102, 218, 13, 204, 234, 177, 160, 33, 199, 50, 84, 117, 29, 90, 22, 28, 26, 146, 6, 44, 34, 10, 33, 122, 105, 217, 130, 51, 20, 180, 222, 153, 111, 232, 212, 95, 109, 181, 175, 22, 193, 86, 20, 149, 164, 196, 48, 144, 188, 57, 76, 148, 225, 176, 236, 115, 142, 181, 98, 103, 32, 28, 46, 205, 28, 123, 73, 147, 192, 239, 192, 40, 75, 220, 154, 9, 28, 41, 79, 149, 112, 58, 113, 120, 130, 44, 138, 149, 183, 155, 30, 69, 145, 224, 153, 141, 137, 56, 117, 193, 168, 121, 192, 138, 7, 60, 198, 125, 244, 38, 187, 167, 146, 193, 138, 230, 193, 254, 186, 135, 155, 236, 84, 129, 44, 42, 255, 160, 18, 151, 59, 112, 10, 212, 142, 39, 16, 120, 40, 8, 100, 38, 134, 0, 167, 170, 48, 158, 170, 16, 152, 117, 10, 15, 138, 82, 46, 185, 41, 87, 123, 65, 47, 120, 85, 97, 54, 136, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141, 117, 48, 12, 171, 6, 102, 236, 18, 148, 241, 74, 235, 7, 67, 76, 152, 132, 186, 68, 0, 62, 205, 180, 131, 145, 131, 130, 26, 36, 133, 167, 198, 62, 42, 46, 166, 201, 196, 118, 252, 73, 55, 176, 19, 201, 147, 167, 147, 214, 192, 171, 153, 96, 105, 91, 168, 56, 246, 73, 218, 83, 156, 163, 208];
-        // let ct = [172, 89, 19, 214, 179, 124, 155, 49, 104, 173, 61, 185, 200, 57, 5, 59, 91, 244, 145, 164, 161, 125, 23, 214, 133, 56, 75, 172, 238, 222, 17, 239, 192, 222, 88, 192, 166, 197, 228, 165, 139, 217, 48, 234, 113, 8, 42, 77, 183, 47, 21, 169, 49, 191, 215, 123, 191, 76, 155, 61, 235, 213, 128, 215, 144, 16, 194, 240, 159, 46, 115, 185, 32, 81, 64, 143, 7, 107, 29, 52, 197, 33, 245, 242, 95, 190, 142, 199, 81, 157, 7, 250, 131, 223, 62, 94, 144, 165, 200, 214, 193, 144, 213, 148, 179, 173, 113, 132, 194, 184, 173, 164, 200, 50, 139, 229, 52, 96, 63, 190, 153, 253, 11, 123, 110, 75, 36, 208, 141, 145, 187, 10, 174, 99, 23, 236, 225, 177, 112, 108, 86, 86, 65, 245, 61, 210, 157, 97, 17, 165, 57, 149, 254, 103, 213, 6, 76, 123, 24, 217, 44, 211, 170, 37, 34, 58, 177, 179, 201, 232, 168, 36, 134, 97, 22, 44, 245, 107, 194, 197, 225, 164, 15, 18, 109, 133, 24, 254, 95, 229, 226, 148, 21, 118, 224, 112, 187, 219, 61, 47, 243, 223, 9, 76, 47, 204, 195, 24, 31, 210, 239, 133, 136, 123, 169, 138, 122, 65, 159, 187, 112, 78, 214, 75, 18, 54, 168, 116, 228, 227, 69, 97, 255, 73, 4, 54, 168, 34, 42, 101, 8, 211, 52, 156, 177, 36, 169, 95, 121, 248, 244, 220, 95, 166, 71, 3, 186, 141, 56, 118, 24, 74, 66, 67, 31, 217, 78, 225, 20, 198, 244, 16, 139, 12, 76, 205, 115, 93, 64, 147, 107, 230, 97, 152, 166, 15, 70, 129, 114, 118, 181, 109, 102, 7, 71, 58, 88, 217, 17, 72, 200, 127, 228, 218, 7, 19, 151, 161, 98, 21, 72, 210, 19, 208, 80, 51, 37, 10, 153, 146, 64, 35, 6, 155, 87, 133, 171, 162, 78, 68, 77, 130, 190, 250, 44, 219, 220, 209, 203, 174, 248, 203, 100, 212, 22, 215, 149, 222, 72, 209, 195, 80, 122, 197, 220, 245, 238, 152, 74, 126, 228, 105, 145, 221, 20, 93, 190, 80, 59, 124, 125, 111, 182, 204, 235, 208, 43, 177, 192, 12, 90, 81, 88, 217, 141, 216, 26, 212, 146, 172, 11, 154, 214, 94, 95, 243, 183, 66, 9, 204, 240, 67, 113, 105, 176, 111, 253, 128, 201, 154, 247, 130, 12, 78, 52, 121, 108, 98, 113, 16, 163, 37, 53, 177, 23, 115, 169, 244, 15, 136, 223, 32, 179, 91, 225, 29, 162, 200, 194, 231, 155, 54, 219, 140, 243, 151, 84, 132, 111, 151, 227, 105, 185, 59, 104, 175, 47, 249, 237, 30, 174, 21, 160, 155, 220, 177, 198, 51, 220, 92, 5, 161, 179, 232, 249, 242, 156, 67, 126, 67, 30, 107, 156, 79, 108, 13, 75, 40, 77, 88, 94, 231, 229, 120, 122, 12, 201, 102, 236, 134, 117, 143, 110, 211, 63, 38, 50, 97, 91, 43, 165, 119, 69, 224, 209, 248, 64, 74, 43, 211, 226, 64, 89, 31, 252, 119, 93, 252, 92, 159, 96, 178, 7, 113, 59, 124, 9, 200, 158, 87, 2, 61, 72, 171, 213, 81, 8, 58, 28, 148, 33, 183, 18, 41, 36, 82, 230, 131, 125, 58, 62, 35, 46, 50, 119, 205, 232, 244, 31, 137, 21, 223, 201, 108, 72, 96, 62, 88, 98, 187, 37, 73, 224, 34, 138, 34, 86, 229, 131, 151, 221, 33, 149, 169, 82, 19, 211, 5, 15, 79, 67, 220, 114, 168, 192, 161, 11, 117, 197, 160, 24, 87, 113, 231, 186, 159, 195, 66, 221, 191, 193, 125, 10, 59, 85, 82, 200, 234, 248, 163, 134, 120, 13, 220, 72, 167, 155, 61, 168, 215, 31, 72, 125, 129, 55, 153, 196, 156, 240, 19, 103, 171, 195, 67, 255, 63, 28, 204, 188, 216, 209, 79, 253, 9, 106, 60, 155, 83, 218, 205, 164, 28, 27, 72, 36, 253, 118, 52, 208, 42, 255, 175, 200, 245, 201, 145, 156, 111, 130, 151, 254, 52, 58, 142, 224, 158, 177, 103, 133, 180, 29, 62, 25, 246, 119, 233, 180, 89, 236, 29, 144, 219, 222, 159, 25, 91, 50, 76, 232, 114, 213, 250, 38, 58, 107, 93, 205, 125, 186, 67, 202, 124, 202, 253, 184, 160, 193, 226, 204, 121, 213, 78, 184, 112, 73, 182, 153, 32, 174, 255, 226, 235, 129, 53, 81, 45, 188, 252, 109, 198, 134, 233, 108, 124, 159, 16, 81, 119, 170, 236, 101, 21, 211, 72, 235, 50, 236, 87, 159, 50, 115, 30, 18, 203, 86, 147, 27, 207, 242, 221, 136, 136, 118, 98, 155, 152, 183, 91, 18, 232, 85, 205, 238, 71, 134, 11, 91, 200, 241, 251, 12, 0, 201, 203, 111, 208, 145, 251, 124, 221, 191, 234, 221, 215, 148, 3, 4, 168, 171, 27, 170, 59, 143, 193, 172, 102, 238, 161, 56, 172, 136, 132, 219, 186, 17, 40, 133, 222, 94, 57, 251, 25, 238, 168, 254, 84, 68, 211, 66, 72, 205, 246, 176, 44, 187, 228, 88, 166, 209, 198, 208, 155, 47, 58, 81, 5, 47, 87, 210, 130, 40, 144, 210, 141, 212, 57, 31, 76, 11, 63, 30, 161, 137, 160, 145, 36, 100, 203, 212, 31, 213, 102, 196, 138, 154, 100, 84, 194, 220, 165, 127, 40, 194, 30, 112, 254, 86, 222, 8, 49, 126, 103, 76, 143, 241, 217, 243, 226, 37, 204, 197, 189, 31, 201, 250, 165, 140, 155, 171, 157, 84, 144, 14, 109, 113, 203, 209, 66, 101, 100, 90, 119, 154, 72, 86, 201, 194, 105, 3, 53, 127, 149, 236, 99, 44, 77, 224, 136, 178, 71, 205, 130, 26, 246, 58, 189, 154, 36, 117, 145, 224, 208, 215, 16, 35, 77, 178, 105, 166, 101, 195, 118, 249, 116, 237, 189, 94, 108, 172, 91, 167, 209, 241, 158, 46, 77, 108, 170, 63, 189, 77, 211, 195, 119, 2, 200, 208, 3, 0, 5, 28, 22, 191, 11, 160, 250, 157, 208, 167, 48, 146, 46, 238, 15, 247, 227, 174, 129, 127, 92, 186, 115, 79, 42, 40, 16, 87, 144, 219, 61, 26, 176, 46, 154, 204, 118, 62, 95, 210, 188, 4, 71, 172, 50, 225, 31, 160, 38, 18, 230, 249, 135, 229, 247, 119, 202, 46, 122, 195, 101, 81, 207, 98, 100, 156, 41, 72, 60];
-
-        // /// 512
-        // let sk = [214, 236, 205, 99, 90, 79, 25, 216, 2, 86, 188, 156, 163, 194, 61, 235, 120, 62, 46, 22, 147, 126, 161, 81, 225, 54, 100, 141, 180, 207, 161, 115, 198, 24, 85, 43, 87, 34, 28, 227, 228, 50, 68, 208, 120, 245, 199, 122, 71, 177, 162, 123, 35, 188, 122, 252, 43, 10, 121, 145, 207, 90, 10, 142, 113, 44, 189, 156, 47, 65, 37, 10, 107, 235, 124, 172, 59, 192, 228, 43, 180, 70, 243, 81, 31, 138, 54, 155, 96, 130, 186, 68, 119, 48, 92, 202, 173, 152, 100, 188, 58, 74, 21, 166, 73, 86, 68, 54, 128, 40, 164, 89, 123, 167, 140, 178, 122, 134, 230, 67, 51, 137, 15, 39, 131, 168, 32, 42, 63, 160, 24, 177, 0, 209, 203, 196, 150, 34, 225, 106, 129, 138, 104, 29, 36, 42, 150, 79, 248, 133, 155, 99, 123, 89, 160, 71, 244, 12, 204, 213, 214, 103, 112, 192, 143, 178, 178, 194, 235, 83, 140, 108, 210, 48, 183, 146, 68, 142, 228, 172, 190, 187, 199, 255, 87, 167, 152, 178, 150, 181, 150, 200, 88, 232, 87, 96, 249, 110, 142, 176, 202, 193, 56, 12, 250, 32, 2, 77, 74, 170, 86, 196, 128, 49, 228, 118, 127, 197, 68, 217, 130, 167, 45, 19, 95, 86, 194, 77, 94, 21, 127, 218, 226, 64, 16, 195, 184, 166, 194, 106, 47, 135, 19, 232, 39, 17, 241, 56, 144, 107, 2, 13, 142, 244, 162, 103, 107, 22, 33, 251, 56, 201, 86, 85, 18, 37, 156, 109, 156, 165, 79, 100, 207, 95, 75, 8, 96, 235, 1, 108, 172, 166, 0, 125, 183, 158, 88, 144, 217, 218, 135, 75, 243, 11, 197, 59, 186, 61, 225, 126, 207, 72, 197, 45, 208, 40, 233, 43, 180, 237, 215, 94, 193, 185, 82, 254, 213, 150, 225, 187, 21, 7, 179, 100, 79, 149, 110, 212, 232, 195, 82, 182, 30, 220, 116, 15, 206, 230, 92, 217, 7, 85, 37, 98, 163, 27, 182, 137, 143, 12, 23, 188, 248, 148, 83, 123, 148, 214, 193, 143, 39, 10, 155, 139, 118, 10, 91, 226, 155, 164, 230, 31, 154, 8, 115, 69, 147, 81, 23, 67, 48, 199, 168, 99, 182, 215, 136, 196, 137, 91, 182, 32, 88, 184, 213, 152, 47, 229, 37, 248, 135, 68, 191, 168, 127, 59, 242, 35, 169, 103, 103, 77, 150, 1, 242, 44, 2, 42, 20, 155, 223, 196, 91, 238, 85, 137, 168, 2, 153, 104, 118, 71, 28, 54, 171, 111, 163, 30, 236, 48, 180, 50, 74, 165, 160, 60, 178, 159, 147, 115, 142, 117, 25, 177, 235, 57, 182, 155, 112, 146, 169, 89, 15, 202, 135, 137, 151, 138, 97, 81, 29, 245, 171, 127, 56, 128, 66, 85, 98, 81, 81, 3, 78, 117, 36, 76, 124, 244, 106, 65, 226, 167, 68, 112, 21, 196, 16, 150, 131, 113, 66, 113, 252, 185, 127, 232, 161, 194, 89, 79, 136, 97, 97, 245, 195, 109, 2, 43, 196, 60, 105, 42, 222, 86, 137, 59, 51, 65, 230, 58, 1, 218, 135, 4, 82, 251, 113, 41, 204, 164, 41, 106, 111, 171, 73, 188, 158, 85, 26, 212, 10, 179, 6, 124, 103, 147, 98, 195, 227, 197, 49, 145, 250, 119, 231, 83, 202, 105, 192, 146, 218, 211, 0, 50, 214, 101, 162, 55, 206, 85, 241, 4, 64, 80, 193, 0, 11, 130, 145, 193, 75, 63, 202, 80, 8, 53, 113, 27, 197, 61, 242, 117, 153, 100, 32, 137, 47, 172, 138, 32, 134, 131, 67, 44, 8, 84, 50, 13, 112, 121, 133, 179, 91, 166, 174, 160, 41, 15, 51, 16, 138, 41, 69, 14, 220, 198, 150, 19, 160, 13, 26, 164, 154, 51, 4, 197, 105, 30, 180, 214, 195, 192, 243, 45, 74, 180, 126, 33, 24, 65, 105, 181, 166, 233, 250, 137, 66, 76, 191, 196, 152, 18, 48, 68, 121, 212, 65, 21, 116, 50, 11, 69, 161, 15, 33, 163, 82, 244, 18, 196, 174, 67, 5, 160, 154, 128, 29, 138, 110, 33, 22, 35, 195, 32, 122, 61, 137, 62, 152, 204, 24, 112, 150, 137, 240, 64, 86, 24, 69, 204, 164, 44, 21, 74, 43, 4, 201, 18, 195, 157, 196, 131, 30, 20, 66, 31, 162, 169, 219, 195, 34, 204, 116, 42, 167, 140, 143, 27, 124, 61, 172, 117, 188, 89, 108, 134, 151, 217, 109, 26, 246, 115, 101, 182, 33, 198, 231, 98, 95, 196, 75, 153, 215, 88, 79, 56, 205, 40, 54, 12, 197, 98, 90, 144, 91, 150, 175, 18, 147, 14, 213, 181, 254, 42, 130, 252, 90, 167, 220, 75, 130, 159, 227, 118, 53, 241, 63, 90, 242, 166, 211, 8, 29, 173, 135, 135, 133, 105, 138, 10, 169, 20, 55, 76, 78, 67, 184, 159, 9, 74, 120, 146, 170, 20, 154, 56, 180, 156, 6, 160, 104, 216, 41, 168, 210, 73, 231, 83, 163, 117, 208, 151, 160, 241, 98, 230, 195, 164, 223, 232, 199, 151, 97, 65, 12, 96, 94, 211, 137, 154, 63, 196, 67, 120, 225, 79, 40, 135, 158, 143, 20, 128, 119, 230, 188, 59, 178, 174, 86, 23, 140, 73, 22, 17, 191, 106, 175, 95, 154, 156, 185, 181, 101, 146, 35, 0, 121, 64, 188, 214, 248, 162, 50, 128, 165, 96, 21, 51, 14, 133, 119, 37, 149, 135, 177, 38, 6, 244, 201, 55, 234, 19, 96, 108, 179, 187, 4, 96, 102, 173, 41, 66, 97, 226, 178, 32, 34, 188, 199, 70, 120, 165, 82, 5, 112, 216, 142, 76, 235, 66, 105, 38, 49, 231, 227, 113, 28, 75, 47, 213, 52, 127, 3, 40, 89, 131, 64, 203, 60, 101, 200, 245, 90, 192, 39, 22, 131, 16, 148, 203, 110, 185, 15, 23, 91, 23, 61, 156, 101, 3, 41, 170, 245, 19, 99, 54, 51, 187, 44, 230, 133, 142, 116, 71, 171, 196, 27, 111, 176, 109, 168, 120, 37, 114, 195, 50, 176, 150, 96, 54, 105, 38, 191, 82, 158, 216, 202, 170, 98, 67, 204, 219, 21, 43, 54, 186, 110, 71, 199, 20, 20, 92, 134, 245, 179, 182, 29, 232, 78, 241, 71, 13, 3, 250, 1, 53, 227, 81, 148, 250, 31, 179, 188, 134, 15, 165, 0, 209, 41, 154, 238, 136, 206, 86, 5, 67, 118, 193, 25, 156, 85, 61, 217, 10, 141, 111, 156, 199, 99, 200, 17, 208, 198, 109, 166, 248, 81, 171, 241, 5, 102, 53, 163, 74, 104, 170, 120, 21, 134, 143, 21, 58, 58, 92, 119, 252, 200, 177, 235, 24, 7, 251, 246, 42, 111, 180, 59, 53, 87, 0, 231, 130, 48, 148, 58, 43, 161, 225, 27, 24, 19, 69, 177, 27, 77, 70, 38, 110, 123, 53, 159, 7, 74, 80, 12, 136, 87, 215, 155, 166, 15, 100, 38, 45, 102, 44, 205, 156, 132, 137, 164, 193, 157, 246, 116, 55, 219, 25, 63, 149, 185, 118, 81, 129, 217, 21, 34, 98, 177, 22, 111, 151, 190, 83, 73, 127, 0, 28, 177, 190, 121, 2, 77, 106, 34, 137, 188, 199, 4, 225, 177, 216, 33, 1, 83, 102, 163, 204, 138, 72, 78, 107, 194, 225, 241, 184, 137, 241, 147, 35, 227, 16, 26, 160, 154, 217, 234, 98, 186, 64, 5, 3, 155, 191, 181, 153, 128, 85, 249, 63, 191, 119, 177, 68, 51, 17, 109, 89, 88, 66, 38, 84, 218, 218, 17, 39, 33, 63, 2, 183, 135, 23, 165, 160, 69, 66, 113, 213, 176, 192, 37, 23, 166, 194, 122, 60, 54, 16, 16, 29, 117, 60, 9, 162, 85, 113, 119, 84, 119, 220, 19, 178, 228, 4, 219, 73, 101, 185, 169, 53, 3, 48, 199, 58, 138, 54, 66, 211, 154, 248, 162, 56, 57, 171, 133, 198, 53, 91, 18, 242, 121, 248, 73, 129, 60, 40, 13, 84, 197, 145, 62, 153, 182, 148, 106, 10, 175, 1, 44, 140, 171, 2, 83, 150, 178, 85, 240, 2, 216, 55, 199, 97, 212, 42, 74, 235, 56, 197, 244, 86, 170, 247, 158, 22, 39, 0, 198, 180, 4, 142, 202, 111, 154, 115, 103, 249, 2, 56, 214, 123, 207, 142, 106, 13, 138, 85, 60, 7, 21, 34, 249, 210, 57, 78, 40, 72, 61, 32, 72, 190, 42, 143, 156, 140, 142, 57, 153, 26, 65, 39, 60, 126, 172, 174, 252, 106, 48, 139, 232, 112, 180, 91, 65, 23, 100, 18, 149, 74, 26, 15, 216, 61, 54, 42, 90, 178, 136, 102, 61, 236, 84, 86, 182, 40, 109, 11, 44, 236, 176, 25, 34, 251, 61, 71, 56, 2, 234, 43, 134, 99, 155, 206, 2, 69, 3, 57, 38, 28, 255, 177, 20, 225, 231, 37, 233, 6, 119, 130, 106, 22, 136, 246, 134, 178, 154, 120, 119, 156, 152, 34, 49, 93, 175, 197, 87, 83, 233, 140, 142, 211, 34, 31, 43, 50, 32, 128, 92, 138, 40, 152, 51, 85, 32, 125, 163, 111, 183, 47, 155, 200, 92, 10, 19, 185, 208, 65, 88, 111, 213, 131, 254, 177, 42, 253, 90, 64, 45, 211, 59, 67, 84, 63, 95, 164, 235, 67, 108, 141, 35, 73, 123, 49, 39, 154, 139, 113, 95, 145, 47, 48, 161, 129, 102, 98, 112, 145, 59, 189, 114, 89, 142, 185, 205, 101, 166, 218, 104, 15, 92, 107, 62, 42, 46, 166, 201, 196, 118, 252, 73, 55, 176, 19, 201, 147, 167, 147, 214, 192, 171, 153, 96, 105, 91, 168, 56, 246, 73, 218, 83, 156, 163, 208];
-        // let ct = [200, 50, 206, 162, 119, 220, 251, 74, 162, 49, 81, 219, 43, 88, 198, 236, 14, 179, 206, 176, 27, 61, 11, 106, 161, 48, 162, 249, 75, 69, 21, 87, 210, 74, 14, 220, 246, 82, 93, 63, 76, 58, 96, 33, 74, 71, 114, 16, 91, 33, 190, 209, 212, 16, 94, 69, 103, 41, 10, 214, 76, 20, 179, 46, 95, 70, 119, 209, 118, 18, 85, 155, 219, 224, 91, 219, 78, 25, 164, 230, 248, 12, 238, 175, 162, 152, 201, 92, 66, 165, 112, 190, 227, 109, 148, 164, 209, 32, 232, 60, 197, 36, 218, 6, 185, 197, 110, 135, 96, 252, 92, 194, 195, 163, 60, 118, 141, 116, 46, 203, 205, 71, 66, 226, 64, 194, 187, 245, 242, 155, 167, 201, 65, 218, 94, 203, 4, 209, 51, 192, 143, 102, 43, 184, 72, 16, 236, 106, 10, 144, 232, 7, 95, 147, 68, 73, 246, 162, 78, 77, 140, 132, 239, 251, 157, 147, 194, 50, 40, 110, 145, 19, 103, 85, 139, 210, 179, 255, 58, 69, 128, 197, 227, 181, 151, 144, 165, 80, 166, 62, 63, 148, 90, 103, 69, 189, 62, 226, 106, 38, 95, 7, 52, 206, 207, 135, 221, 75, 79, 61, 111, 176, 118, 182, 178, 13, 230, 67, 179, 110, 156, 132, 236, 157, 81, 3, 175, 237, 121, 102, 114, 178, 75, 198, 236, 152, 12, 157, 31, 115, 219, 99, 68, 84, 170, 151, 118, 104, 223, 204, 201, 136, 197, 255, 159, 28, 98, 85, 79, 167, 180, 56, 114, 42, 46, 219, 24, 14, 172, 211, 124, 133, 217, 7, 243, 243, 161, 239, 139, 61, 148, 220, 169, 253, 247, 82, 43, 135, 0, 230, 76, 189, 11, 25, 154, 152, 146, 180, 20, 77, 179, 19, 25, 49, 80, 27, 122, 24, 80, 67, 47, 189, 58, 149, 69, 84, 202, 254, 184, 241, 222, 26, 145, 103, 101, 45, 208, 104, 194, 108, 97, 209, 217, 165, 29, 78, 29, 197, 215, 232, 187, 29, 54, 139, 121, 136, 49, 203, 196, 176, 81, 237, 197, 91, 43, 98, 54, 202, 106, 208, 213, 158, 210, 181, 185, 235, 221, 124, 30, 129, 111, 140, 33, 17, 219, 25, 143, 190, 175, 37, 177, 52, 254, 158, 87, 221, 135, 71, 158, 145, 54, 87, 43, 24, 27, 48, 123, 52, 111, 236, 48, 190, 140, 142, 3, 32, 148, 236, 3, 203, 253, 46, 229, 208, 247, 183, 5, 94, 231, 52, 111, 42, 67, 148, 57, 148, 151, 209, 220, 147, 153, 240, 87, 126, 130, 48, 231, 172, 6, 123, 156, 171, 173, 153, 59, 110, 15, 90, 197, 18, 200, 112, 254, 118, 40, 111, 196, 189, 23, 59, 3, 65, 246, 39, 246, 149, 198, 70, 70, 73, 220, 1, 233, 66, 42, 189, 132, 113, 233, 136, 224, 40, 160, 63, 82, 23, 193, 107, 65, 105, 89, 129, 82, 240, 220, 249, 215, 139, 211, 144, 188, 157, 106, 230, 42, 136, 94, 226, 145, 241, 69, 225, 198, 211, 125, 67, 42, 18, 144, 57, 133, 66, 56, 182, 43, 237, 0, 171, 61, 173, 56, 154, 248, 197, 56, 90, 182, 19, 250, 24, 249, 183, 70, 51, 36, 216, 46, 18, 220, 204, 244, 83, 39, 246, 32, 18, 130, 88, 98, 20, 220, 90, 28, 76, 18, 87, 47, 234, 78, 71, 171, 233, 193, 2, 58, 154, 30, 206, 63, 196, 96, 25, 229, 233, 90, 66, 133, 81, 177, 221, 193, 8, 191, 230, 138, 17, 114, 41, 235, 174, 129, 243, 130, 158, 112, 206, 149, 62, 129, 165, 137, 134, 219, 20, 82, 232, 25, 81, 139, 16, 149, 200, 134, 216, 110, 18, 57, 86, 121, 227, 50, 39, 215, 36, 16, 43, 56, 33, 30, 129, 47, 149, 137, 114, 101, 212, 11, 98, 202, 164, 53, 67, 136, 130, 6, 158, 51, 192, 189, 248, 134, 209, 75, 111, 38, 182, 19, 23, 88, 21, 19, 234, 98, 92, 223, 239, 77, 33, 52, 79, 156, 16, 25, 46, 111, 140, 3, 250, 53, 74, 6, 124, 40, 149, 114, 213, 6, 45, 76, 154, 211, 4, 143, 84, 19, 130, 17, 175, 161, 159, 234, 243, 158, 164, 68, 243, 119, 74, 116, 166, 168, 104, 225, 38, 183, 165, 199, 180, 225, 219, 185, 207, 28, 148, 158, 42, 7, 44, 255, 108, 148, 60, 27, 114, 60, 214, 39, 60, 75, 196, 70, 137, 145, 228, 164, 206, 202, 110, 28, 75, 205, 66, 250, 111, 199, 199, 48, 2];
-
-        let mut ss = [0u8; KYBER_SSBYTES];
-        let inst0 = syst.cvr.read();
-        crypto_kem_dec(&mut ss, &ct, &sk);
-        let inst1 = syst.cvr.read();
-        let keypair_ticks = if inst0 > inst1 {
-            inst0 - inst1 - 2
-        }else {
-            inst0 + (12000000 - inst1)
-        };
-        if min >= keypair_ticks {
-            min = keypair_ticks;
-        }else if max <= keypair_ticks {
-            max = keypair_ticks;
+fn main() -> ! {
+    let mut board = board::init();
+    hprintln!("Board: {}", board::NAME);
+    hprintln!("Clock: {}", board::CLOCK);
+    hprintln!("Parameter set: {}", PARAMETER_SET);
+    hprintln!("Stack: {}", if cfg!(feature = "ccram-stack") { "CCRAM" } else { "RAM" });
+
+    let mut sinks = RESULTS.map(|(path, format)| match Sink::create(path, format, board::CLOCK) {
+        Ok(sink) => Some(sink),
+        Err(e) => {
+            hprintln!("Not writing {}: {:?}", path, e);
+            None
         }
-        sum += keypair_ticks;
+    });
 
+    if let Some(dwt) = board.dwt.as_mut() {
+        hprintln!("Counter overhead: DWT {}", dwt.overhead());
+        report("DWT", &suite::kem(dwt, &mut board.rng, measure_stack), &mut sinks);
+        #[cfg(feature = "m4-dsp-ntt")]
+        report("DWT", &suite::ntt(dwt), &mut sinks);
+    }
+    hprintln!("Counter overhead: SysTick {}", board.systick.overhead());
+    report("SysTick", &suite::kem(&mut board.systick, &mut board.rng, measure_stack), &mut sinks);
+    #[cfg(feature = "m4-dsp-ntt")]
+    report("SysTick", &suite::ntt(&mut board.systick), &mut sinks);
+    for sink in IntoIterator::into_iter(sinks).flatten() {
+        if let Err(e) = sink.finish() {
+            hprintln!("Writing the results failed: {:?}", e);
+        }
     }
-    // hprintln!("{:?}", res);
-    hprintln!("Ticks: MIN: {} MAX: {} AVG: {}", min,max,sum/1_000);
-    loop {}
+    hprintln!("Shared secrets match");
+    exit::success()
 }
 
 #[exception]
 #[allow(non_snake_case)]
 fn SysTick() {
-    cortex_m::interrupt::free(|cs| {
-        if let Some(counter) = &mut *TICK_COUNTER.borrow(cs).borrow_mut() {
-            counter.clock_tick();
-        }
-    })
-}
\ No newline at end of file
+    counter::systick_wrapped();
+}
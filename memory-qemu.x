@@ -0,0 +1,17 @@
+/* QEMU `mps2-an386` (Cortex-M4), selected by the `qemu` feature */
+MEMORY
+{
+  /* NOTE K = KiBi = 1024 bytes */
+  /* ZBT SSRAM1, holds the code like the flash of the board */
+  FLASH : ORIGIN = 0x00000000, LENGTH = 4M
+  /* ZBT SSRAM2 and SSRAM3, the last 64K stand in for the CCRAM of the board */
+  RAM : ORIGIN = 0x20000000, LENGTH = 4M - 64K
+  CCRAM : ORIGIN = 0x20000000 + 4M - 64K, LENGTH = 64K
+}
+
+/* This is where the call stack will be allocated. */
+/* The stack is of the full descending type. */
+/* NOTE Do NOT modify `_stack_start` unless you know what you are doing */
+_stack_start = ORIGIN(RAM) + LENGTH(RAM);
+
+INCLUDE ccram.x
@@ -11,6 +11,7 @@ MEMORY
 /* NOTE Do NOT modify `_stack_start` unless you know what you are doing */
 _stack_start = ORIGIN(RAM) + LENGTH(RAM);
 
-/* Advanced users can place the stack inthe CCRAM */
-/* which is smaller but faster. */
-/* _stack_start = ORIGIN(CCRAM) + LENGTH(CCRAM); */
\ No newline at end of file
+/* The `ccram-stack` feature moves the stack to the end of the CCRAM, */
+/* which is smaller but faster (see build.rs). */
+
+INCLUDE ccram.x
@@ -0,0 +1,307 @@
+//! Benchmark runner: times a closure with any [`CycleCounter`] and reduces the
+//! samples to summary statistics without heap allocation.
+
+use core::fmt;
+use core::hint::black_box;
+
+use crate::counter::CycleCounter;
+
+/// Maximum number of samples a single [`bench`] run can keep.
+pub const MAX_SAMPLES: usize = 1_000;
+
+/// Fixed-capacity sample buffer.
+pub struct Samples<const N: usize> {
+    buf: [u32; N],
+    len: usize,
+}
+
+impl<const N: usize> Samples<N> {
+    pub const fn new() -> Self {
+        Samples { buf: [0; N], len: 0 }
+    }
+
+    /// Stores a sample, returns `false` once the buffer is full.
+    pub fn push(&mut self, cycles: u64) -> bool {
+        if self.len == N {
+            return false;
+        }
+        self.buf[self.len] = cycles.min(u32::MAX as u64) as u32;
+        self.len += 1;
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_slice(&self) -> &[u32] {
+        &self.buf[..self.len]
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Sorts the samples in place and computes their statistics.
+    pub fn stats(&mut self, name: &'static str) -> Stats {
+        Stats::from_samples(name, &mut self.buf[..self.len])
+    }
+}
+
+impl<const N: usize> Default for Samples<N> {
+    fn default() -> Self {
+        Samples::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    pub name: &'static str,
+    pub iterations: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: u64,
+    pub median: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub stddev: u64,
+    /// Peak stack usage in bytes, when measured.
+    pub stack: Option<usize>,
+}
+
+impl Stats {
+    /// Sorts `samples` and reduces them; an empty slice gives all zeros.
+    pub fn from_samples(name: &'static str, samples: &mut [u32]) -> Stats {
+        let n = samples.len();
+        if n == 0 {
+            return Stats {
+                name,
+                ..Stats::default()
+            };
+        }
+        samples.sort_unstable();
+
+        let sum: u64 = samples.iter().map(|&s| s as u64).sum();
+        let mean = sum / n as u64;
+        // a single squared deviation of `u32` samples can take most of a
+        // `u64`, their sum needs the wider accumulator
+        let variance = samples
+            .iter()
+            .map(|&s| {
+                let d = (s as i64 - mean as i64).unsigned_abs() as u128;
+                d * d
+            })
+            .sum::<u128>()
+            / n as u128;
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] as u64 + samples[n / 2] as u64) / 2
+        } else {
+            samples[n / 2] as u64
+        };
+
+        Stats {
+            name,
+            iterations: n,
+            min: samples[0] as u64,
+            max: samples[n - 1] as u64,
+            mean,
+            median,
+            p90: percentile(samples, 90),
+            p99: percentile(samples, 99),
+            stddev: isqrt(variance as u64),
+            stack: None,
+        }
+    }
+
+    pub fn with_stack(self, bytes: usize) -> Stats {
+        Stats {
+            stack: Some(bytes),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: N: {} MIN: {} MAX: {} MEAN: {} MEDIAN: {} P90: {} P99: {} STDDEV: {}",
+            self.name, self.iterations, self.min, self.max, self.mean, self.median, self.p90, self.p99, self.stddev
+        )?;
+        if let Some(bytes) = self.stack {
+            write!(f, " STACK: {}", bytes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Column titles matching the rows printed through [`Stats::row`].
+pub const TABLE_HEADER: &str =
+    "operation                       min        max       mean     median        p90        p99     stddev      stack";
+
+impl Stats {
+    /// Fixed-width table row, see [`TABLE_HEADER`].
+    pub fn row(&self) -> Row<'_> {
+        Row(self)
+    }
+}
+
+pub struct Row<'a>(&'a Stats);
+
+impl fmt::Display for Row<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.0;
+        write!(
+            f,
+            "{:<24} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            s.name, s.min, s.max, s.mean, s.median, s.p90, s.p99, s.stddev
+        )?;
+        match s.stack {
+            Some(bytes) => write!(f, " {:>10}", bytes),
+            None => write!(f, " {:>10}", "-"),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non empty slice.
+fn percentile(sorted: &[u32], p: usize) -> u64 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1] as u64
+}
+
+/// Integer square root (floor), `f64::sqrt` is not available in `core`.
+fn isqrt(v: u64) -> u64 {
+    if v < 2 {
+        return v;
+    }
+    let mut x = v;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + v / x) / 2;
+    }
+    x
+}
+
+/// Runs `f` `warmup` times untimed, then `iterations` times under `counter`.
+///
+/// At most [`MAX_SAMPLES`] iterations are recorded.
+pub fn bench<C, F, R>(counter: &mut C, name: &'static str, iterations: usize, warmup: usize, mut f: F) -> Stats
+where
+    C: CycleCounter,
+    F: FnMut() -> R,
+{
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let mut samples: Samples<MAX_SAMPLES> = Samples::new();
+    for _ in 0..iterations.min(MAX_SAMPLES) {
+        let (r, cycles) = counter.measure(&mut f);
+        black_box(r);
+        samples.push(cycles);
+    }
+    samples.stats(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(samples: &[u32]) -> Stats {
+        let mut samples = samples.to_vec();
+        Stats::from_samples("test", &mut samples)
+    }
+
+    /// Counts one cycle more for every measurement.
+    struct Ramp {
+        next: u64,
+        last: u64,
+    }
+
+    impl CycleCounter for Ramp {
+        fn start(&mut self) {}
+
+        fn stop(&mut self) {
+            self.next += 1;
+            self.last = self.next;
+        }
+
+        fn elapsed_cycles(&self) -> u64 {
+            self.last
+        }
+
+        fn overhead(&self) -> u64 {
+            0
+        }
+    }
+
+    #[test]
+    fn empty_samples_give_zeros() {
+        let s = stats(&[]);
+        assert_eq!((s.iterations, s.min, s.max, s.mean, s.median, s.stddev), (0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn odd_count() {
+        let s = stats(&[9, 1, 5, 3, 7]);
+        assert_eq!((s.iterations, s.min, s.max, s.mean, s.median), (5, 1, 9, 5, 5));
+        // variance 8
+        assert_eq!(s.stddev, 2);
+    }
+
+    #[test]
+    fn even_count_median_averages_the_middle_pair() {
+        let s = stats(&[4, 1, 3, 2]);
+        assert_eq!(s.median, 2);
+        let s = stats(&[10, 20]);
+        assert_eq!(s.median, 15);
+    }
+
+    #[test]
+    fn nearest_rank_percentiles() {
+        let samples: Vec<u32> = (1..=100).rev().collect();
+        let s = stats(&samples);
+        assert_eq!((s.p90, s.p99), (90, 99));
+
+        let s = stats(&(1..=10).collect::<Vec<u32>>());
+        assert_eq!((s.p90, s.p99), (9, 10));
+
+        let s = stats(&[42]);
+        assert_eq!((s.median, s.p90, s.p99), (42, 42, 42));
+    }
+
+    #[test]
+    fn extreme_samples_do_not_overflow() {
+        let mut samples = [0u32; MAX_SAMPLES];
+        for (i, s) in samples.iter_mut().enumerate() {
+            *s = if i % 2 == 0 { 0 } else { u32::MAX };
+        }
+        let s = stats(&samples);
+        assert_eq!(s.mean, u32::MAX as u64 / 2);
+        assert_eq!(s.stddev, u32::MAX as u64 / 2);
+    }
+
+    #[test]
+    fn isqrt_floors() {
+        for (v, root) in [(0, 0), (1, 1), (2, 1), (3, 1), (4, 2), (8, 2), (9, 3), (99, 9), (100, 10)] {
+            assert_eq!(isqrt(v), root, "isqrt({})", v);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn bench_keeps_at_most_max_samples() {
+        let mut counter = Ramp { next: 0, last: 0 };
+        let s = bench(&mut counter, "ramp", MAX_SAMPLES + 500, 0, || ());
+        assert_eq!(s.iterations, MAX_SAMPLES);
+        assert_eq!((s.min, s.max), (1, MAX_SAMPLES as u64));
+
+        let s = bench(&mut counter, "ramp", 10, 0, || ());
+        assert_eq!(s.iterations, 10);
+    }
+}
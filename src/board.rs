@@ -0,0 +1,78 @@
+//! Board bring-up shared by the examples.
+//!
+//! With the `stm32f407` feature (default) this configures the clocks of the
+//! STM32F407 discovery board; with the `qemu` feature it targets the QEMU
+//! `mps2-an386` machine, which has no STM32 peripherals, no DWT cycle counter
+//! and no hardware RNG.
+
+use crate::clock::{self, ClockProfile};
+use crate::counter::{DwtCounter, SysTickCounter};
+
+#[cfg(all(feature = "stm32f407", feature = "qemu"))]
+compile_error!("the `stm32f407` and `qemu` board features are mutually exclusive");
+
+#[cfg(not(any(feature = "stm32f407", feature = "qemu")))]
+compile_error!("select a board with the `stm32f407` or `qemu` feature");
+
+#[cfg(feature = "stm32f407")]
+pub use crate::rng::HwRng as BoardRng;
+
+/// QEMU has no RNG peripheral: use a fixed-seed CSPRNG instead.
+#[cfg(feature = "qemu")]
+pub use crate::rng::SeededRng as BoardRng;
+
+#[cfg(feature = "stm32f407")]
+pub const NAME: &str = "STM32F407";
+#[cfg(feature = "qemu")]
+pub const NAME: &str = "QEMU mps2-an386";
+
+/// Clock the examples run at; every time conversion goes through it.
+#[cfg(feature = "stm32f407")]
+pub const CLOCK: ClockProfile = clock::HSE8_SYSCLK24;
+#[cfg(feature = "qemu")]
+pub const CLOCK: ClockProfile = clock::QEMU_MPS2;
+
+pub struct Board {
+    pub systick: SysTickCounter,
+    /// `None` where the DWT cycle counter is not available (QEMU).
+    pub dwt: Option<DwtCounter>,
+    pub rng: BoardRng,
+}
+
+#[cfg(feature = "stm32f407")]
+pub fn init() -> Board {
+    use stm32f4xx_hal::{pac, prelude::*};
+
+    unsafe { crate::ccram::init() };
+    let dp = pac::Peripherals::take().unwrap();
+    let rcc = dp.RCC.constrain();
+    let mut cfgr = rcc.cfgr.sysclk(CLOCK.sysclk_hz.Hz());
+    if let Some(hse) = CLOCK.hse_hz {
+        cfgr = cfgr.use_hse(hse.Hz());
+    }
+    if CLOCK.pll48clk {
+        cfgr = cfgr.require_pll48clk();
+    }
+    // also sets the flash latency for the new HCLK
+    let clocks = cfgr.freeze();
+    assert_eq!(clocks.sysclk().raw(), CLOCK.sysclk_hz, "SYSCLK of {} not reachable", CLOCK.name);
+    let rng = BoardRng::new(dp.RNG.constrain(&clocks)).expect("hardware RNG failed its first health check");
+
+    let mut core_periphs = cortex_m::Peripherals::take().unwrap();
+    Board {
+        dwt: Some(DwtCounter::new(&mut core_periphs.DCB, core_periphs.DWT)),
+        systick: SysTickCounter::new(core_periphs.SYST),
+        rng,
+    }
+}
+
+#[cfg(feature = "qemu")]
+pub fn init() -> Board {
+    unsafe { crate::ccram::init() };
+    let core_periphs = cortex_m::Peripherals::take().unwrap();
+    Board {
+        dwt: None,
+        systick: SysTickCounter::new(core_periphs.SYST),
+        rng: BoardRng::default(),
+    }
+}
@@ -0,0 +1,153 @@
+//! FFI to the pqcrystals C reference implementation, compiled by build.rs
+//! with the `c-reference` feature for the same parameter set as pqc_kyber.
+//!
+//! The C code draws its randomness from `randombytes`, implemented here on a
+//! fixed-seed [`SeededRng`], or on fixed coins with [`replay_randombytes`] so
+//! that the C calls get the same coins as the seeded pqc_kyber calls of the
+//! benchmarks. The source sits behind a critical section on the target and a
+//! `std::sync::Mutex` in a host build.
+
+#[cfg(not(feature = "std"))]
+use core::cell::RefCell;
+
+#[cfg(not(feature = "std"))]
+use cortex_m::interrupt::{self, Mutex};
+use pqc_kyber::{KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+use rand_core::RngCore;
+
+use crate::rng::SeededRng;
+
+// The C symbols are prefixed by `KYBER_NAMESPACE`, which depends on `KYBER_K`.
+extern "C" {
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_keypair")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_keypair")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_keypair")]
+    fn c_keypair(pk: *mut u8, sk: *mut u8) -> i32;
+
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_enc")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_enc")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_enc")]
+    fn c_enc(ct: *mut u8, ss: *mut u8, pk: *const u8) -> i32;
+
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_dec")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_dec")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_dec")]
+    fn c_dec(ss: *mut u8, ct: *const u8, sk: *const u8) -> i32;
+
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_ntt")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_ntt")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_ntt")]
+    fn c_ntt(r: *mut i16);
+
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_invntt")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_invntt")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_invntt")]
+    fn c_invntt(r: *mut i16);
+
+    #[cfg_attr(feature = "kyber512", link_name = "pqcrystals_kyber512_ref_basemul")]
+    #[cfg_attr(not(any(feature = "kyber512", feature = "kyber1024")), link_name = "pqcrystals_kyber768_ref_basemul")]
+    #[cfg_attr(feature = "kyber1024", link_name = "pqcrystals_kyber1024_ref_basemul")]
+    fn c_basemul(r: *mut i16, a: *const i16, b: *const i16, zeta: i16);
+}
+
+/// Longest coin sequence [`replay_randombytes`] takes, `d || z` of a keypair.
+pub const MAX_COINS: usize = 64;
+
+// there is a single `Source`, in a static, and no allocator to box it
+#[allow(clippy::large_enum_variant)]
+enum Source {
+    Seeded(SeededRng),
+    /// `coins[..len]` over and over, `next` is where the next call starts.
+    Replay { coins: [u8; MAX_COINS], len: usize, next: usize },
+}
+
+impl Source {
+    fn fill_bytes(&mut self, out: &mut [u8]) {
+        match self {
+            Source::Seeded(rng) => rng.fill_bytes(out),
+            Source::Replay { coins, len, next } => {
+                for b in out.iter_mut() {
+                    *b = coins[*next];
+                    *next = (*next + 1) % *len;
+                }
+            }
+        }
+    }
+}
+
+/// Source of `randombytes`, created on first use.
+#[cfg(not(feature = "std"))]
+static RANDOMBYTES: Mutex<RefCell<Option<Source>>> = Mutex::new(RefCell::new(None));
+#[cfg(feature = "std")]
+static RANDOMBYTES: std::sync::Mutex<Option<Source>> = std::sync::Mutex::new(None);
+
+#[cfg(not(feature = "std"))]
+fn with_randombytes<R>(f: impl FnOnce(&mut Option<Source>) -> R) -> R {
+    interrupt::free(|cs| f(&mut RANDOMBYTES.borrow(cs).borrow_mut()))
+}
+
+#[cfg(feature = "std")]
+fn with_randombytes<R>(f: impl FnOnce(&mut Option<Source>) -> R) -> R {
+    f(&mut RANDOMBYTES.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// `void randombytes(uint8_t *out, size_t outlen)` for the C reference.
+#[no_mangle]
+extern "C" fn randombytes(out: *mut u8, outlen: usize) {
+    let out = unsafe { core::slice::from_raw_parts_mut(out, outlen) };
+    with_randombytes(|source| {
+        source
+            .get_or_insert_with(|| Source::Seeded(SeededRng::default()))
+            .fill_bytes(out)
+    });
+}
+
+/// Restarts `randombytes` from `seed`.
+pub fn seed_randombytes(seed: [u8; 32]) {
+    with_randombytes(|source| *source = Some(Source::Seeded(SeededRng::new(seed))));
+}
+
+/// Makes `randombytes` hand out `coins` again and again, each call going on
+/// where the previous one stopped. The C reference asks for `d` then `z` in a
+/// keypair and for `m` in an encapsulation, so `d || z` and `m` give it the
+/// coins of `crypto_kem_keypair(.., Some((d, z)))` and
+/// `crypto_kem_enc(.., Some(m))`.
+pub fn replay_randombytes(coins: &[u8]) {
+    assert!(!coins.is_empty() && coins.len() <= MAX_COINS, "1 to {} coins", MAX_COINS);
+    let mut buf = [0u8; MAX_COINS];
+    buf[..coins.len()].copy_from_slice(coins);
+    with_randombytes(|source| {
+        *source = Some(Source::Replay {
+            coins: buf,
+            len: coins.len(),
+            next: 0,
+        })
+    });
+}
+
+/// `crypto_kem_keypair`, returns the C status (0 on success).
+pub fn keypair(pk: &mut [u8; KYBER_PUBLICKEYBYTES], sk: &mut [u8; KYBER_SECRETKEYBYTES]) -> i32 {
+    unsafe { c_keypair(pk.as_mut_ptr(), sk.as_mut_ptr()) }
+}
+
+/// `crypto_kem_enc`, returns the C status (0 on success).
+pub fn enc(ct: &mut [u8; KYBER_CIPHERTEXTBYTES], ss: &mut [u8; KYBER_SSBYTES], pk: &[u8; KYBER_PUBLICKEYBYTES]) -> i32 {
+    unsafe { c_enc(ct.as_mut_ptr(), ss.as_mut_ptr(), pk.as_ptr()) }
+}
+
+/// `crypto_kem_dec`, returns the C status (0 on success).
+pub fn dec(ss: &mut [u8; KYBER_SSBYTES], ct: &[u8; KYBER_CIPHERTEXTBYTES], sk: &[u8; KYBER_SECRETKEYBYTES]) -> i32 {
+    unsafe { c_dec(ss.as_mut_ptr(), ct.as_ptr(), sk.as_ptr()) }
+}
+
+pub fn ntt(r: &mut [i16; 256]) {
+    unsafe { c_ntt(r.as_mut_ptr()) }
+}
+
+pub fn invntt(r: &mut [i16; 256]) {
+    unsafe { c_invntt(r.as_mut_ptr()) }
+}
+
+pub fn basemul(r: &mut [i16; 2], a: &[i16; 2], b: &[i16; 2], zeta: i16) {
+    unsafe { c_basemul(r.as_mut_ptr(), a.as_ptr(), b.as_ptr(), zeta) }
+}
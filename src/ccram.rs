@@ -0,0 +1,52 @@
+//! Placement of statics in the 64K core-coupled RAM of the STM32F407.
+//!
+//! CCRAM sits on the D-bus of the core only, so data there never competes
+//! with instruction fetches or DMA. `ccram.x` collects the `.ccram` input
+//! sections into CCRAM with their initial values in flash; [`init`] copies
+//! them, `board::init` does so before anything else.
+
+extern "C" {
+    static mut __sccram: u32;
+    static mut __eccram: u32;
+    static __siccram: u32;
+}
+
+/// Declares a `static` or `static mut` that lives in CCRAM with the `ccram`
+/// feature and in ordinary RAM/flash without it.
+///
+/// ```ignore
+/// kyber_rust::ccram! {
+///     static mut POLY: [i16; 256] = [0; 256];
+/// }
+/// ```
+#[macro_export]
+macro_rules! ccram {
+    ($(#[$attr:meta])* $vis:vis static mut $name:ident: $ty:ty = $init:expr;) => {
+        $(#[$attr])*
+        #[cfg_attr(feature = "ccram", link_section = ".ccram")]
+        $vis static mut $name: $ty = $init;
+    };
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $init:expr;) => {
+        $(#[$attr])*
+        #[cfg_attr(feature = "ccram", link_section = ".ccram")]
+        $vis static $name: $ty = $init;
+    };
+}
+
+/// Copies the initial values of the `.ccram` statics from flash.
+///
+/// # Safety
+///
+/// Must run before any `.ccram` static is accessed, and only once.
+pub unsafe fn init() {
+    let start = core::ptr::addr_of_mut!(__sccram);
+    let end = core::ptr::addr_of_mut!(__eccram);
+    let words = (end as usize - start as usize) / 4;
+    core::ptr::copy_nonoverlapping(core::ptr::addr_of!(__siccram), start, words);
+}
+
+/// First free CCRAM address after the `.ccram` statics, the stack bottom with
+/// the `ccram-stack` feature.
+pub fn end() -> usize {
+    core::ptr::addr_of_mut!(__eccram) as usize
+}
@@ -0,0 +1,94 @@
+//! Clock profiles: the single place the core clock is chosen, and everything
+//! that depends on it (flash latency, cycle to time conversion, how often the
+//! counters wrap) is derived from.
+
+use core::fmt;
+
+use crate::counter::SYST_RELOAD_MAX;
+
+/// Highest SYSCLK of the STM32F407.
+pub const SYSCLK_MAX_HZ: u32 = 168_000_000;
+
+/// HCLK per flash wait state on the STM32F407 at 2.7-3.6 V (RM0090, table 10).
+const FLASH_HZ_PER_WAIT_STATE: u32 = 30_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockProfile {
+    pub name: &'static str,
+    /// External oscillator, `None` to run from the internal one.
+    pub hse_hz: Option<u32>,
+    pub sysclk_hz: u32,
+    /// Whether the 48 MHz PLL output is required, which the RNG needs.
+    pub pll48clk: bool,
+}
+
+/// The original benchmark setup: 8 MHz crystal of the discovery board, 24 MHz core.
+pub const HSE8_SYSCLK24: ClockProfile = ClockProfile::new("hse8-sysclk24", Some(8_000_000), 24_000_000, true);
+
+/// The discovery board at full speed.
+pub const HSE8_SYSCLK168: ClockProfile = ClockProfile::new("hse8-sysclk168", Some(8_000_000), SYSCLK_MAX_HZ, true);
+
+/// The 25 MHz reference clock of the QEMU `mps2-an386` machine; QEMU does not
+/// emulate timing, so it only serves the time conversions.
+pub const QEMU_MPS2: ClockProfile = ClockProfile::new("qemu-mps2", None, 25_000_000, false);
+
+impl ClockProfile {
+    /// Rejects, at compile time when used in a `const`, a SYSCLK the chip
+    /// cannot run at.
+    pub const fn new(name: &'static str, hse_hz: Option<u32>, sysclk_hz: u32, pll48clk: bool) -> ClockProfile {
+        assert!(sysclk_hz > 0 && sysclk_hz <= SYSCLK_MAX_HZ, "SYSCLK must be at most 168 MHz");
+        ClockProfile { name, hse_hz, sysclk_hz, pll48clk }
+    }
+
+    /// Profile of a host build, where nothing is configured: `hz` is the rate
+    /// of its counter, e.g. the time-stamp counter, and only serves the time
+    /// conversions and the reports.
+    pub const fn host(name: &'static str, hz: u32) -> ClockProfile {
+        ClockProfile { name, hse_hz: None, sysclk_hz: hz, pll48clk: false }
+    }
+
+    /// Flash latency the HAL programs for this SYSCLK (HCLK = SYSCLK).
+    pub const fn flash_wait_states(&self) -> u32 {
+        (self.sysclk_hz - 1) / FLASH_HZ_PER_WAIT_STATE
+    }
+
+    /// Core cycles, as counted by DWT and SysTick, in microseconds.
+    pub const fn cycles_to_us(&self, cycles: u64) -> u64 {
+        cycles * 1_000_000 / self.sysclk_hz as u64
+    }
+
+    /// Core cycles in milliseconds.
+    pub const fn cycles_to_ms(&self, cycles: u64) -> u64 {
+        cycles * 1_000 / self.sysclk_hz as u64
+    }
+
+    /// Time between two SysTick exceptions with the reload used by
+    /// [`SysTickCounter`](crate::counter::SysTickCounter).
+    pub const fn systick_wrap_ms(&self) -> u64 {
+        self.cycles_to_ms(SYST_RELOAD_MAX as u64 + 1)
+    }
+
+    /// Time until the 32 bit DWT cycle counter wraps.
+    pub const fn dwt_wrap_ms(&self) -> u64 {
+        self.cycles_to_ms(u32::MAX as u64 + 1)
+    }
+}
+
+impl fmt::Display for ClockProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: SYSCLK {} MHz from ", self.name, self.sysclk_hz / 1_000_000)?;
+        match self.hse_hz {
+            Some(hz) => write!(f, "{} MHz HSE", hz / 1_000_000)?,
+            None => f.write_str("the internal oscillator")?,
+        }
+        if self.pll48clk {
+            f.write_str(", PLL48CLK")?;
+        }
+        write!(
+            f,
+            ", {} flash wait states, SysTick wraps every {} ms",
+            self.flash_wait_states(),
+            self.systick_wrap_ms()
+        )
+    }
+}
@@ -0,0 +1,304 @@
+//! Cycle counters used to time the Kyber operations.
+//!
+//! Every timing source implements [`CycleCounter`], so a benchmark is written
+//! once against the trait and can then be run with SysTick, with the DWT
+//! cycle counter or, on a host build, with the x86-64 time-stamp counter and
+//! `std::time`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use cortex_m::peripheral::{syst::SystClkSource, DCB, DWT, SCB, SYST};
+
+/// Largest value accepted by the 24 bit SysTick reload register.
+pub const SYST_RELOAD_MAX: u32 = 0x00FF_FFFF;
+
+/// Number of empty start/stop pairs used to calibrate [`CycleCounter::overhead`].
+const CALIBRATION_ROUNDS: usize = 16;
+
+pub trait CycleCounter {
+    /// Takes the first timestamp of a measurement.
+    fn start(&mut self);
+
+    /// Takes the second timestamp of a measurement.
+    fn stop(&mut self);
+
+    /// Cycles between the last `start` and `stop`, without the counter overhead.
+    fn elapsed_cycles(&self) -> u64;
+
+    /// Cycles spent by an empty `start`/`stop` pair.
+    fn overhead(&self) -> u64;
+
+    /// Runs `f` between `start` and `stop` and returns its result together
+    /// with the elapsed cycles.
+    fn measure<R, F: FnOnce() -> R>(&mut self, f: F) -> (R, u64) {
+        self.start();
+        let r = f();
+        self.stop();
+        (r, self.elapsed_cycles())
+    }
+}
+
+/// Smallest raw start/stop distance seen over [`CALIBRATION_ROUNDS`] rounds.
+fn calibrate<F: FnMut() -> u64>(mut raw: F) -> u64 {
+    (0..CALIBRATION_ROUNDS).map(|_| raw()).min().unwrap_or(0)
+}
+
+/// SysTick wraps seen so far, bumped by [`systick_wrapped`].
+static SYST_WRAPS: AtomicU32 = AtomicU32::new(0);
+
+/// Must be called from the `SysTick` exception handler so that
+/// [`SysTickCounter`] can extend the 24 bit counter.
+pub fn systick_wrapped() {
+    SYST_WRAPS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub struct SysTickCounter {
+    #[allow(dead_code)]
+    /// keep syst as a resource
+    syst: SYST,
+    reload: u32,
+    start: u64,
+    end: u64,
+    overhead: u64,
+}
+
+impl SysTickCounter {
+    pub fn new(mut syst: SYST) -> SysTickCounter {
+        syst.set_clock_source(SystClkSource::Core);
+        // To make the timer wrap every N ticks set the reload value to N - 1
+        syst.set_reload(SYST_RELOAD_MAX);
+        syst.clear_current();
+        syst.enable_counter();
+        syst.enable_interrupt();
+
+        let mut counter = SysTickCounter {
+            syst,
+            reload: SYST_RELOAD_MAX,
+            start: 0,
+            end: 0,
+            overhead: 0,
+        };
+        counter.overhead = calibrate(|| {
+            counter.start();
+            counter.stop();
+            counter.end.saturating_sub(counter.start)
+        });
+        counter
+    }
+
+    /// Ticks since the counter was enabled, wraps included.
+    fn now(&self) -> u64 {
+        let period = self.reload as u64 + 1;
+        loop {
+            let wraps = SYST_WRAPS.load(Ordering::Relaxed);
+            let current = SYST::get_current();
+            let pending = SCB::is_pendst_pending();
+            // the exception may fire between the reads
+            if wraps != SYST_WRAPS.load(Ordering::Relaxed) {
+                continue;
+            }
+            // a reload whose exception is still pending (interrupts masked, or
+            // a higher priority context) is not counted yet, and `current` may
+            // predate it: read it again, after the reload
+            if pending {
+                return (wraps as u64 + 1) * period + (self.reload - SYST::get_current()) as u64;
+            }
+            return wraps as u64 * period + (self.reload - current) as u64;
+        }
+    }
+}
+
+impl CycleCounter for SysTickCounter {
+    fn start(&mut self) {
+        self.start = self.now();
+    }
+
+    fn stop(&mut self) {
+        self.end = self.now();
+    }
+
+    fn elapsed_cycles(&self) -> u64 {
+        self.end.saturating_sub(self.start).saturating_sub(self.overhead)
+    }
+
+    fn overhead(&self) -> u64 {
+        self.overhead
+    }
+}
+
+pub struct DwtCounter {
+    #[allow(dead_code)]
+    /// keep dwt as a resource
+    dwt: DWT,
+    start: u32,
+    end: u32,
+    overhead: u64,
+}
+
+impl DwtCounter {
+    pub fn new(dcb: &mut DCB, mut dwt: DWT) -> DwtCounter {
+        // CYCCNT only counts once the trace block is enabled
+        dcb.enable_trace();
+        DWT::unlock();
+        dwt.set_cycle_count(0);
+        dwt.enable_cycle_counter();
+
+        let mut counter = DwtCounter {
+            dwt,
+            start: 0,
+            end: 0,
+            overhead: 0,
+        };
+        counter.overhead = calibrate(|| {
+            counter.start();
+            counter.stop();
+            counter.end.wrapping_sub(counter.start) as u64
+        });
+        counter
+    }
+}
+
+impl CycleCounter for DwtCounter {
+    fn start(&mut self) {
+        self.start = DWT::cycle_count();
+    }
+
+    fn stop(&mut self) {
+        self.end = DWT::cycle_count();
+    }
+
+    fn elapsed_cycles(&self) -> u64 {
+        // CYCCNT is 32 bit wide: at 168 MHz it wraps every ~25 s, so a single
+        // wrapping subtraction is enough for any KEM operation
+        (self.end.wrapping_sub(self.start) as u64).saturating_sub(self.overhead)
+    }
+
+    fn overhead(&self) -> u64 {
+        self.overhead
+    }
+}
+
+/// Host counter: "cycles" are nanoseconds of `std::time::Instant`.
+#[cfg(feature = "std")]
+pub struct StdCounter {
+    origin: std::time::Instant,
+    start: std::time::Duration,
+    end: std::time::Duration,
+    overhead: u64,
+}
+
+#[cfg(feature = "std")]
+impl StdCounter {
+    pub fn new() -> StdCounter {
+        let mut counter = StdCounter {
+            origin: std::time::Instant::now(),
+            start: std::time::Duration::ZERO,
+            end: std::time::Duration::ZERO,
+            overhead: 0,
+        };
+        counter.overhead = calibrate(|| {
+            counter.start();
+            counter.stop();
+            counter.end.saturating_sub(counter.start).as_nanos() as u64
+        });
+        counter
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdCounter {
+    fn default() -> Self {
+        StdCounter::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl CycleCounter for StdCounter {
+    fn start(&mut self) {
+        self.start = self.origin.elapsed();
+    }
+
+    fn stop(&mut self) {
+        self.end = self.origin.elapsed();
+    }
+
+    fn elapsed_cycles(&self) -> u64 {
+        (self.end.saturating_sub(self.start).as_nanos() as u64).saturating_sub(self.overhead)
+    }
+
+    fn overhead(&self) -> u64 {
+        self.overhead
+    }
+}
+
+/// Host counter on x86-64: ticks of the time-stamp counter, which runs at a
+/// constant rate on current CPUs whatever the core frequency.
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub struct RdtscCounter {
+    start: u64,
+    end: u64,
+    overhead: u64,
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+impl RdtscCounter {
+    /// Time `frequency` counts the TSC against `Instant` for.
+    const CALIBRATION: std::time::Duration = std::time::Duration::from_millis(100);
+
+    pub fn new() -> RdtscCounter {
+        let mut counter = RdtscCounter { start: 0, end: 0, overhead: 0 };
+        counter.overhead = calibrate(|| {
+            counter.start();
+            counter.stop();
+            counter.end.saturating_sub(counter.start)
+        });
+        counter
+    }
+
+    /// `lfence` keeps `rdtsc` from being executed before the instructions
+    /// that precede it, or after the ones that follow.
+    fn now() -> u64 {
+        use core::arch::x86_64::{_mm_lfence, _rdtsc};
+        unsafe {
+            _mm_lfence();
+            let t = _rdtsc();
+            _mm_lfence();
+            t
+        }
+    }
+
+    /// TSC ticks per second, measured against `std::time::Instant`.
+    pub fn frequency(&self) -> u64 {
+        let origin = std::time::Instant::now();
+        let start = Self::now();
+        while origin.elapsed() < Self::CALIBRATION {}
+        let ticks = Self::now() - start;
+        (ticks as u128 * 1_000_000_000 / origin.elapsed().as_nanos()) as u64
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+impl Default for RdtscCounter {
+    fn default() -> Self {
+        RdtscCounter::new()
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+impl CycleCounter for RdtscCounter {
+    fn start(&mut self) {
+        self.start = Self::now();
+    }
+
+    fn stop(&mut self) {
+        self.end = Self::now();
+    }
+
+    fn elapsed_cycles(&self) -> u64 {
+        self.end.saturating_sub(self.start).saturating_sub(self.overhead)
+    }
+
+    fn overhead(&self) -> u64 {
+        self.overhead
+    }
+}
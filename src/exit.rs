@@ -0,0 +1,73 @@
+//! How the examples end: a normal completion exits with `EXIT_SUCCESS`, a
+//! failed check or a panic with `EXIT_FAILURE`, so QEMU (and OpenOCD with
+//! semihosting) hand a meaningful exit code to scripts.
+//!
+//! The crate provides the `#[panic_handler]` on the target: linking
+//! `kyber_rust` replaces `panic_halt`. The message and location are printed
+//! on the semihosting console before exiting.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use cortex_m_semihosting::debug;
+
+/// Ends the program with `EXIT_SUCCESS` or `EXIT_FAILURE`.
+///
+/// Without a host to take the exit request the core keeps idling, so a
+/// debugger session stays open.
+#[cfg(not(feature = "std"))]
+pub fn status(success: bool) -> ! {
+    debug::exit(if success { debug::EXIT_SUCCESS } else { debug::EXIT_FAILURE });
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
+/// Host build: the process exit code.
+#[cfg(feature = "std")]
+pub fn status(success: bool) -> ! {
+    std::process::exit(if success { 0 } else { 1 })
+}
+
+pub fn success() -> ! {
+    status(true)
+}
+
+pub fn failure() -> ! {
+    status(false)
+}
+
+/// Unwraps `result`, or prints what failed and ends the program with
+/// `EXIT_FAILURE`.
+pub fn check<T, E: fmt::Debug>(what: &str, result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            report(format_args!("{} failed: {:?}", what, e));
+            failure()
+        }
+    }
+}
+
+/// One line on the semihosting stderr.
+#[cfg(not(feature = "std"))]
+fn report(args: fmt::Arguments) {
+    if let Ok(mut stderr) = cortex_m_semihosting::hio::hstderr() {
+        use core::fmt::Write;
+        writeln!(stderr, "{}", args).ok();
+    }
+}
+
+#[cfg(feature = "std")]
+fn report(args: fmt::Arguments) {
+    eprintln!("{}", args);
+}
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    // no SysTick wraps or other interrupts while reporting
+    cortex_m::interrupt::disable();
+    report(format_args!("{}", info));
+    failure()
+}
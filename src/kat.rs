@@ -0,0 +1,218 @@
+//! Known-Answer-Test support: the AES-256 CTR DRBG of the NIST PQC `rng.c`
+//! and the vectors parsed from the round 3 `.rsp` files by `build.rs`.
+//!
+//! Every `.rsp` entry only stores the 48 byte DRBG seed. The reference
+//! implementation seeds `randombytes` with it and then draws, in this order,
+//! `d` (indcpa keypair), `z` (implicit rejection value) and `m` (encaps coins),
+//! which are exactly the `bufs` accepted by `crypto_kem_keypair` and
+//! `crypto_kem_enc`.
+
+use pqc_kyber::{KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+
+pub struct Kat {
+    pub count: usize,
+    pub seed: [u8; 48],
+    pub pk: [u8; KYBER_PUBLICKEYBYTES],
+    pub sk: [u8; KYBER_SECRETKEYBYTES],
+    pub ct: [u8; KYBER_CIPHERTEXTBYTES],
+    pub ss: [u8; KYBER_SSBYTES],
+}
+
+// `VECTORS: &[Kat]`, empty when `kat/` holds no `.rsp` for this parameter set
+include!(concat!(env!("OUT_DIR"), "/kat_vectors.rs"));
+
+/// `randombytes` state: `randombytes_init` + `randombytes` of the NIST `rng.c`.
+pub struct Drbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl Drbg {
+    /// `randombytes_init(seed, NULL, 256)`
+    pub fn new(seed: &[u8; 48]) -> Drbg {
+        let mut drbg = Drbg {
+            key: [0; 32],
+            v: [0; 16],
+        };
+        drbg.update(Some(seed));
+        drbg
+    }
+
+    /// `randombytes(out, out.len())`
+    pub fn fill(&mut self, out: &mut [u8]) {
+        let aes = Aes256::new(&self.key);
+        for chunk in out.chunks_mut(16) {
+            increment(&mut self.v);
+            let block = aes.encrypt(&self.v);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+
+    /// `AES256_CTR_DRBG_Update`
+    fn update(&mut self, provided: Option<&[u8; 48]>) {
+        let aes = Aes256::new(&self.key);
+        let mut temp = [0u8; 48];
+        for block in temp.chunks_mut(16) {
+            increment(&mut self.v);
+            block.copy_from_slice(&aes.encrypt(&self.v));
+        }
+        if let Some(data) = provided {
+            for (t, d) in temp.iter_mut().zip(data.iter()) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+}
+
+/// Big-endian increment of the DRBG counter.
+fn increment(v: &mut [u8; 16]) {
+    for b in v.iter_mut().rev() {
+        *b = b.wrapping_add(1);
+        if *b != 0 {
+            break;
+        }
+    }
+}
+
+/// Table based AES-256 encryption, only what the DRBG needs.
+struct Aes256 {
+    round_keys: [[u8; 16]; 15],
+}
+
+#[rustfmt::skip]
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ (((x >> 7) & 1) * 0x1b)
+}
+
+impl Aes256 {
+    fn new(key: &[u8; 32]) -> Aes256 {
+        let mut w = [[0u8; 4]; 60];
+        for (i, word) in w.iter_mut().take(8).enumerate() {
+            word.copy_from_slice(&key[4 * i..4 * i + 4]);
+        }
+        let mut rcon = 1u8;
+        for i in 8..60 {
+            let mut t = w[i - 1];
+            if i % 8 == 0 {
+                t = [SBOX[t[1] as usize] ^ rcon, SBOX[t[2] as usize], SBOX[t[3] as usize], SBOX[t[0] as usize]];
+                rcon = xtime(rcon);
+            } else if i % 8 == 4 {
+                t = [SBOX[t[0] as usize], SBOX[t[1] as usize], SBOX[t[2] as usize], SBOX[t[3] as usize]];
+            }
+            for j in 0..4 {
+                w[i][j] = w[i - 8][j] ^ t[j];
+            }
+        }
+
+        let mut round_keys = [[0u8; 16]; 15];
+        for (r, rk) in round_keys.iter_mut().enumerate() {
+            for c in 0..4 {
+                rk[4 * c..4 * c + 4].copy_from_slice(&w[4 * r + c]);
+            }
+        }
+        Aes256 { round_keys }
+    }
+
+    fn encrypt(&self, input: &[u8; 16]) -> [u8; 16] {
+        let mut s = *input;
+        add_round_key(&mut s, &self.round_keys[0]);
+        for round in 1..15 {
+            // SubBytes
+            for b in s.iter_mut() {
+                *b = SBOX[*b as usize];
+            }
+            // ShiftRows, the state is column major
+            let t = s;
+            for c in 0..4 {
+                for r in 0..4 {
+                    s[4 * c + r] = t[4 * ((c + r) % 4) + r];
+                }
+            }
+            // MixColumns, skipped in the last round
+            if round != 14 {
+                for c in 0..4 {
+                    let a = [s[4 * c], s[4 * c + 1], s[4 * c + 2], s[4 * c + 3]];
+                    let all = a[0] ^ a[1] ^ a[2] ^ a[3];
+                    for r in 0..4 {
+                        s[4 * c + r] = a[r] ^ all ^ xtime(a[r] ^ a[(r + 1) % 4]);
+                    }
+                }
+            }
+            add_round_key(&mut s, &self.round_keys[round]);
+        }
+        s
+    }
+}
+
+fn add_round_key(s: &mut [u8; 16], rk: &[u8; 16]) {
+    for (b, k) in s.iter_mut().zip(rk.iter()) {
+        *b ^= k;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        assert_eq!(s.len(), 2 * N);
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// FIPS-197 appendix C.3.
+    #[test]
+    fn aes256_known_answer() {
+        let key = hex::<32>("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let plaintext = hex::<16>("00112233445566778899aabbccddeeff");
+        let ciphertext = hex::<16>("8ea2b7ca516745bfeafc49904b496089");
+        assert_eq!(Aes256::new(&key).encrypt(&plaintext), ciphertext);
+    }
+
+    /// `PQCgenKAT_kem.c` seeds the DRBG with the bytes 0..48 and draws the
+    /// seed of every `.rsp` entry from it, 48 bytes each.
+    #[test]
+    fn drbg_gives_the_kat_seeds() {
+        let mut entropy = [0u8; 48];
+        for (i, b) in entropy.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut drbg = Drbg::new(&entropy);
+        let mut seed = [0u8; 48];
+        drbg.fill(&mut seed);
+        assert_eq!(
+            seed,
+            hex::<48>("061550234d158c5ec95595fe04ef7a25767f2e24cc2bc479d09d86dc9abcfde7056a8c266f9ef97ed08541dbd2e1ffa1")
+        );
+        drbg.fill(&mut seed);
+        assert_eq!(
+            seed,
+            hex::<48>("d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556ac81adde6aeeb4a5a875c3bfcadfa958f")
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Leakage assessment in the style of dudect: cycle counts of two input
+//! classes are fed to an online Welch's t-test, `|t| > 4.5` is taken as
+//! evidence of a timing difference between the classes.
+
+use crate::counter::CycleCounter;
+
+/// dudect's threshold on `|t|`.
+pub const THRESHOLD: f64 = 4.5;
+
+/// Running mean and sum of squared deviations of one class (Welford).
+#[derive(Clone, Copy, Default)]
+struct Moments {
+    n: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn push(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.n < 2 {
+            return 0.0;
+        }
+        self.m2 / (self.n - 1) as f64
+    }
+}
+
+/// Welch's t-test between class `0` and class `1`, updated one measurement at
+/// a time so no sample has to be stored.
+#[derive(Clone, Copy, Default)]
+pub struct WelchTest {
+    classes: [Moments; 2],
+}
+
+impl WelchTest {
+    pub const fn new() -> Self {
+        let empty = Moments { n: 0, mean: 0.0, m2: 0.0 };
+        WelchTest { classes: [empty; 2] }
+    }
+
+    /// Records the cycles of one call with an input of `class` (0 or 1).
+    pub fn push(&mut self, class: usize, cycles: u64) {
+        self.classes[class].push(cycles as f64);
+    }
+
+    /// Measurements recorded for `class`.
+    pub fn count(&self, class: usize) -> u64 {
+        self.classes[class].n
+    }
+
+    /// Mean cycles of `class`.
+    pub fn mean(&self, class: usize) -> f64 {
+        self.classes[class].mean
+    }
+
+    fn t_squared(&self) -> f64 {
+        let [a, b] = &self.classes;
+        let se2 = a.variance() / a.n as f64 + b.variance() / b.n as f64;
+        if a.n < 2 || b.n < 2 || se2 == 0.0 {
+            return 0.0;
+        }
+        let diff = a.mean - b.mean;
+        diff * diff / se2
+    }
+
+    /// The t statistic, 0 until both classes have two measurements with some
+    /// variance.
+    pub fn t(&self) -> f64 {
+        let t = sqrt(self.t_squared());
+        if self.classes[0].mean < self.classes[1].mean {
+            -t
+        } else {
+            t
+        }
+    }
+
+    /// `|t|` exceeds [`THRESHOLD`].
+    pub fn leaks(&self) -> bool {
+        self.t_squared() > THRESHOLD * THRESHOLD
+    }
+}
+
+/// `f64::sqrt` is not available without `std`, Newton's method is enough for
+/// reporting `t`.
+fn sqrt(v: f64) -> f64 {
+    if v <= 0.0 {
+        return 0.0;
+    }
+    let mut x = if v > 1.0 { v } else { 1.0 };
+    for _ in 0..64 {
+        let y = 0.5 * (x + v / x);
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    x
+}
+
+/// Times `f` once and adds the cycles to `test` under `class`.
+pub fn measure<C, F, R>(counter: &mut C, test: &mut WelchTest, class: usize, f: F) -> R
+where
+    C: CycleCounter,
+    F: FnOnce() -> R,
+{
+    let (r, cycles) = counter.measure(f);
+    test.push(class, cycles);
+    r
+}
@@ -0,0 +1,49 @@
+//! Shared measurement harness for the Kyber benchmarks in `examples/`.
+//!
+//! With the `std` feature, and no board feature, the harness builds for the
+//! host instead (see the `host_speed_evaluation` example).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(
+    all(feature = "kyber512", feature = "kyber768"),
+    all(feature = "kyber512", feature = "kyber1024"),
+    all(feature = "kyber768", feature = "kyber1024"),
+))]
+compile_error!(
+    "only one of the `kyber512`, `kyber768` and `kyber1024` features can be enabled, `kyber1024` is a default feature"
+);
+
+/// Kyber parameter set selected through the crate features.
+#[cfg(feature = "kyber512")]
+pub const PARAMETER_SET: &str = "Kyber512";
+#[cfg(not(any(feature = "kyber512", feature = "kyber1024")))]
+pub const PARAMETER_SET: &str = "Kyber768";
+#[cfg(feature = "kyber1024")]
+pub const PARAMETER_SET: &str = "Kyber1024";
+
+/// `opt-level` of the profile the crate was built with, e.g. `"3"` or `"z"`.
+pub const OPT_LEVEL: &str = env!("KYBER_RUST_OPT_LEVEL");
+
+pub mod bench;
+#[cfg(not(feature = "std"))]
+pub mod board;
+#[cfg(feature = "c-reference")]
+pub mod c_ref;
+pub mod ccram;
+pub mod clock;
+pub mod counter;
+pub mod exit;
+pub mod kat;
+pub mod leakage;
+pub mod ntt_asm;
+#[cfg(feature = "m4-dsp-ntt")]
+pub mod ntt_m4;
+pub mod poly;
+pub mod report;
+pub mod rng;
+#[cfg(target_os = "none")]
+pub mod stack;
+pub mod suite;
+#[cfg(not(target_os = "none"))]
+pub mod transpiled;
@@ -1,15 +1,19 @@
-#![no_std]
-#![no_main]
+#![cfg_attr(target_os = "none", no_std)]
+#![cfg_attr(target_os = "none", no_main)]
 
 // pick a panicking behavior
+#[cfg(target_os = "none")]
 use panic_halt as _; // you can put a breakpoint on `rust_begin_unwind` to catch panics
 // use panic_abort as _; // requires nightly
 // use panic_itm as _; // logs messages over ITM; requires ITM support
 // use panic_semihosting as _; // logs messages to the host stderr; requires a debugger
 
+#[cfg(target_os = "none")]
 use cortex_m::asm;
+#[cfg(target_os = "none")]
 use cortex_m_rt::entry;
 
+#[cfg(target_os = "none")]
 #[entry]
 fn main() -> ! {
     asm::nop(); // To not have main optimize to abort in release mode, remove when you add code
@@ -18,3 +22,7 @@ fn main() -> ! {
         // your code goes here
     }
 }
+
+// host builds (tests, benches) still build this target
+#[cfg(not(target_os = "none"))]
+fn main() {}
@@ -0,0 +1,109 @@
+//! Reference re-implementation of the NTT of `pqc_kyber::ntt` as compiled in
+//! `assembly/ntt_rust_kyber1024.S`, written from the listing by hand.
+//!
+//! It is not a build of `transpilation/ntt_asm_to_rust.rs`: that transcription
+//! is kept as it was produced and does not compile (undeclared registers,
+//! assignments to immutable bindings, code after diverging loops), nor does it
+//! describe a working NTT.
+//!
+//! The layers follow the blocks of the listing: `.LBB1_1` to `.LBB1_24` are
+//! loops over the butterflies of len 128, 64, 32 and 16, `.LBB1_29`,
+//! `.LBB1_33` and `.LBB1_37` handle the groups of len 8, 4 and 2 with their
+//! butterflies unrolled. Each butterfly repeats the register arithmetic of the
+//! listing, so a difference against `pqc_kyber::ntt` points at the reading of
+//! the listing and not at a re-derived algorithm.
+
+/// `.Lanon.e014a0186272dfb360e2d177b7b7066f.61`, the zetas in Montgomery form.
+#[rustfmt::skip]
+pub const ZETAS: [i16; 128] = [
+    -1044,  -758,  -359, -1517,  1493,  1422,   287,   202,
+     -171,   622,  1577,   182,   962, -1202, -1474,  1468,
+      573, -1325,   264,   383,  -829,  1458, -1602,  -130,
+     -681,  1017,   732,   608, -1542,   411,  -205, -1571,
+     1223,   652,  -552,  1015, -1293,  1491,  -282, -1544,
+      516,    -8,  -320,  -666, -1618, -1162,   126,  1469,
+     -853,   -90,  -271,   830,   107, -1421,  -247,  -951,
+     -398,   961, -1508,  -725,   448, -1065,   677, -1275,
+    -1103,   430,   555,   843, -1251,   871,  1550,   105,
+      422,   587,   177,  -235,  -291,  -460,  1574,  1653,
+     -246,   778,  1159,  -147,  -777,  1483,  -602,  1119,
+    -1590,   644,  -872,   349,   418,   329,  -156,   -75,
+      817,  1097,   603,   610,  1322, -1285, -1465,   384,
+    -1215,  -136,  1218, -1335,  -874,   220, -1187, -1659,
+    -1185, -1530, -1278,   794, -1510,  -854,  -870,   478,
+     -108,  -308,   996,   991,   958, -1460,  1522,  1628,
+];
+
+/// `movs r1, #0; movt r1, #62209`: QINV in the top half.
+const QINV_HI: u32 = 62209 << 16;
+
+/// `mvn r12, #3328`: -KYBER_Q.
+const NEG_Q: u32 = !3328;
+
+/// `smlabt rd, rn, rm, ra`: bottom half of `rn` times top half of `rm`, plus `ra`.
+#[inline(always)]
+fn smlabt(rn: u32, rm: u32, ra: u32) -> u32 {
+    ((rn as i16 as i32).wrapping_mul((rm >> 16) as i16 as i32) as u32).wrapping_add(ra)
+}
+
+/// One butterfly as emitted in every layer of the listing.
+#[inline(always)]
+fn butterfly(r: &mut [i16; 256], j: usize, len: usize, zeta: u32) {
+    let r0 = (r[j + len] as i32 as u32).wrapping_mul(zeta); // ldrsh; mul r0, r0, r8
+    let r1 = r0.wrapping_mul(QINV_HI); // muls r1, r0, r1
+    let r0 = smlabt(NEG_Q, r1, r0); // smlabt r0, r12, r1, r0
+    let r1 = r[j] as u16 as u32; // ldrh
+    r[j] = r1.wrapping_add(r0 >> 16) as i16; // add.w r3, r1, r0, lsr #16; strh
+    r[j + len] = r1.wrapping_sub(r0 >> 16) as i16; // sub.w r0, r1, r0, lsr #16; strh
+}
+
+/// Forward NTT, same contract as `pqc_kyber::ntt`: standard order input,
+/// bit-reversed order output, no final reduction.
+pub fn ntt_from_asm(r: &mut [i16; 256]) {
+    // r10 in the listing, the zeta index shared by all layers
+    let mut k = 1;
+    let mut next_zeta = || {
+        let zeta = ZETAS[k] as i32 as u32; // ldrsh.w r8, [r0, r10, lsl #1]
+        k += 1;
+        zeta
+    };
+
+    // .LBB1_1 to .LBB1_24: one loop over the butterflies of each layer
+    for &len in &[128, 64, 32, 16] {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = next_zeta();
+            for j in start..start + len {
+                butterfly(r, j, len, zeta);
+            }
+        }
+    }
+
+    // .LBB1_29: len 8, the eight butterflies of a group unrolled
+    for start in (0..256).step_by(16) {
+        let zeta = next_zeta(); // ldrsh r5, [r12], #2
+        butterfly(r, start, 8, zeta); // [r6, #16] and [r1, #-30]
+        butterfly(r, start + 1, 8, zeta); // [r6, #18] and [r6, #2]
+        butterfly(r, start + 2, 8, zeta); // [r6, #20] and [r6, #4]
+        butterfly(r, start + 3, 8, zeta); // [r6, #22] and [r6, #6]
+        butterfly(r, start + 4, 8, zeta); // [r6, #24] and [r6, #8]
+        butterfly(r, start + 5, 8, zeta); // [r6, #26] and [r6, #10]
+        butterfly(r, start + 6, 8, zeta); // [r6, #28] and [r6, #12]
+        butterfly(r, start + 7, 8, zeta); // [r1] and [r6, #14]
+    }
+
+    // .LBB1_33: len 4
+    for start in (0..256).step_by(8) {
+        let zeta = next_zeta(); // ldrsh r3, [r12], #2
+        butterfly(r, start, 4, zeta); // [r2, #8] and [r6, #-14]
+        butterfly(r, start + 1, 4, zeta); // [r2, #10] and [r2, #2]
+        butterfly(r, start + 2, 4, zeta); // [r2, #12] and [r2, #4]
+        butterfly(r, start + 3, 4, zeta); // [r6] and [r2, #6]
+    }
+
+    // .LBB1_37: len 2
+    for start in (0..256).step_by(4) {
+        let zeta = next_zeta(); // ldrsh r3, [r12], #2
+        butterfly(r, start, 2, zeta); // [r1] and [r1, #-4]
+        butterfly(r, start + 1, 2, zeta); // [r1, #2] and [r1, #-10]
+    }
+}
@@ -0,0 +1,369 @@
+//! NTT, inverse NTT and base multiplication written with the Cortex-M4 DSP
+//! instructions used as intrinsics (`m4-dsp-ntt` feature).
+//!
+//! Two coefficients are kept packed in one 32 bit word and processed by the
+//! packed-halfword instructions (`smulbb`, `smultb`, `sadd16`, `ssub16`,
+//! `pkhtb`, ...). Layers are merged 3 + 3 + 1: eight words go through three
+//! layers of butterflies between one load and one store.
+//!
+//! This is not a pqm4-style assembly backend. On `thumbv7em` every DSP
+//! instruction is its own `asm!` block, which the compiler cannot schedule
+//! across, and the words are packed from and unpacked to bounds-checked `i16`
+//! indexing instead of `ldr`/`str` of whole words. On other targets (host
+//! tests) the same functions are computed in plain Rust, so the algorithm is
+//! tested bit for bit against `pqc_kyber` off target. Reductions are done
+//! where the reference does them, which keeps the results identical to `ntt`,
+//! `invntt` and `poly::poly_basemul`.
+
+use crate::ntt_asm;
+
+crate::ccram! {
+    /// The zetas read by the butterflies, in CCRAM with the `ccram` feature.
+    static ZETAS: [i16; 128] = ntt_asm::ZETAS;
+}
+
+/// `-KYBER_Q` in the bottom half, `QINV` (62209, i.e. -3327) in the top half.
+const QQINV: u32 = (62209 << 16) | (-3329i16 as u16 as u32);
+
+/// Barrett constant `((1 << 26) + KYBER_Q / 2) / KYBER_Q`.
+const BARRETT_V: u32 = 20159;
+
+const KYBER_Q: i32 = 3329;
+
+/// mont^2/128, scales the inverse NTT output.
+const INVNTT_F: u32 = 1441;
+
+mod dsp {
+    //! One function per instruction, `asm!` on ARM and plain Rust elsewhere.
+
+    #[cfg(target_arch = "arm")]
+    macro_rules! dsp_op {
+        ($name:ident, $insn:literal) => {
+            #[inline(always)]
+            pub fn $name(a: u32, b: u32) -> u32 {
+                let r: u32;
+                unsafe {
+                    core::arch::asm!(
+                        concat!($insn, " {r}, {a}, {b}"),
+                        r = lateout(reg) r,
+                        a = in(reg) a,
+                        b = in(reg) b,
+                        options(pure, nomem, nostack, preserves_flags),
+                    )
+                };
+                r
+            }
+        };
+    }
+
+    #[cfg(target_arch = "arm")]
+    dsp_op!(smulbb, "smulbb");
+    #[cfg(target_arch = "arm")]
+    dsp_op!(smulbt, "smulbt");
+    #[cfg(target_arch = "arm")]
+    dsp_op!(smultb, "smultb");
+    #[cfg(target_arch = "arm")]
+    dsp_op!(smultt, "smultt");
+    #[cfg(target_arch = "arm")]
+    dsp_op!(sadd16, "sadd16");
+    #[cfg(target_arch = "arm")]
+    dsp_op!(ssub16, "ssub16");
+
+    /// `smlabb r, a, b, acc`
+    #[cfg(target_arch = "arm")]
+    #[inline(always)]
+    pub fn smlabb(a: u32, b: u32, acc: u32) -> u32 {
+        let r: u32;
+        unsafe {
+            core::arch::asm!(
+                "smlabb {r}, {a}, {b}, {acc}",
+                r = lateout(reg) r,
+                a = in(reg) a,
+                b = in(reg) b,
+                acc = in(reg) acc,
+                options(pure, nomem, nostack, preserves_flags),
+            )
+        };
+        r
+    }
+
+    /// `pkhbt r, a, b, lsl #16`: bottom half of `a`, bottom half of `b` on top.
+    #[cfg(target_arch = "arm")]
+    #[inline(always)]
+    pub fn pkhbt(a: u32, b: u32) -> u32 {
+        let r: u32;
+        unsafe {
+            core::arch::asm!(
+                "pkhbt {r}, {a}, {b}, lsl #16",
+                r = lateout(reg) r,
+                a = in(reg) a,
+                b = in(reg) b,
+                options(pure, nomem, nostack, preserves_flags),
+            )
+        };
+        r
+    }
+
+    /// `pkhtb r, a, b, asr #16`: top half of `a`, top half of `b` at the bottom.
+    #[cfg(target_arch = "arm")]
+    #[inline(always)]
+    pub fn pkhtb(a: u32, b: u32) -> u32 {
+        let r: u32;
+        unsafe {
+            core::arch::asm!(
+                "pkhtb {r}, {a}, {b}, asr #16",
+                r = lateout(reg) r,
+                a = in(reg) a,
+                b = in(reg) b,
+                options(pure, nomem, nostack, preserves_flags),
+            )
+        };
+        r
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    #[inline(always)]
+    fn lo(x: u32) -> i32 {
+        x as u16 as i16 as i32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    #[inline(always)]
+    fn hi(x: u32) -> i32 {
+        (x >> 16) as u16 as i16 as i32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn smulbb(a: u32, b: u32) -> u32 {
+        (lo(a) * lo(b)) as u32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn smulbt(a: u32, b: u32) -> u32 {
+        (lo(a) * hi(b)) as u32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn smultb(a: u32, b: u32) -> u32 {
+        (hi(a) * lo(b)) as u32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn smultt(a: u32, b: u32) -> u32 {
+        (hi(a) * hi(b)) as u32
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn smlabb(a: u32, b: u32, acc: u32) -> u32 {
+        ((lo(a) * lo(b)) as u32).wrapping_add(acc)
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn sadd16(a: u32, b: u32) -> u32 {
+        let lo = (a as u16).wrapping_add(b as u16) as u32;
+        let hi = ((a >> 16) as u16).wrapping_add((b >> 16) as u16) as u32;
+        lo | hi << 16
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn ssub16(a: u32, b: u32) -> u32 {
+        let lo = (a as u16).wrapping_sub(b as u16) as u32;
+        let hi = ((a >> 16) as u16).wrapping_sub((b >> 16) as u16) as u32;
+        lo | hi << 16
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn pkhbt(a: u32, b: u32) -> u32 {
+        (a & 0xFFFF) | (b << 16)
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    pub fn pkhtb(a: u32, b: u32) -> u32 {
+        (a & 0xFFFF_0000) | (b >> 16)
+    }
+}
+
+use dsp::*;
+
+/// Montgomery reduction of a 32 bit product, the result is the top half.
+#[inline(always)]
+fn montgomery(a: u32) -> u32 {
+    let t = smulbt(a, QQINV); // a * QINV, only the bottom half matters
+    smlabb(QQINV, t, a) // a - t * KYBER_Q
+}
+
+/// Both halves of `a` times the bottom half of `zeta`, in Montgomery form.
+#[inline(always)]
+fn fqmul2(a: u32, zeta: u32) -> u32 {
+    let lo = montgomery(smulbb(a, zeta));
+    let hi = montgomery(smultb(a, zeta));
+    pkhtb(hi, lo)
+}
+
+/// Barrett reduction of both halves.
+#[inline(always)]
+fn barrett2(a: u32) -> u32 {
+    let lo = ((smulbb(a, BARRETT_V) as i32 + (1 << 25)) >> 26) * KYBER_Q;
+    let hi = ((smultb(a, BARRETT_V) as i32 + (1 << 25)) >> 26) * KYBER_Q;
+    ssub16(a, pkhbt(lo as u32, hi as u32))
+}
+
+/// Cooley-Tukey butterfly on two packed pairs.
+#[inline(always)]
+fn ct_butterfly(a0: &mut u32, a1: &mut u32, zeta: u32) {
+    let t = fqmul2(*a1, zeta);
+    *a1 = ssub16(*a0, t);
+    *a0 = sadd16(*a0, t);
+}
+
+/// Gentleman-Sande butterfly on two packed pairs.
+#[inline(always)]
+fn gs_butterfly(a0: &mut u32, a1: &mut u32, zeta: u32) {
+    let t = *a0;
+    *a0 = barrett2(sadd16(t, *a1));
+    *a1 = fqmul2(ssub16(*a1, t), zeta);
+}
+
+fn zeta(k: usize) -> u32 {
+    ZETAS[k] as u16 as u32
+}
+
+#[inline(always)]
+fn load(r: &[i16; 256], word: usize) -> u32 {
+    r[2 * word] as u16 as u32 | (r[2 * word + 1] as u16 as u32) << 16
+}
+
+#[inline(always)]
+fn store(r: &mut [i16; 256], word: usize, w: u32) {
+    r[2 * word] = w as i16;
+    r[2 * word + 1] = (w >> 16) as i16;
+}
+
+/// Three merged forward layers on the eight words `base + m * stride`.
+///
+/// `k` is the zeta index of the first layer, the following layers use the
+/// next `2` and `4` zetas of their block as in the reference.
+#[inline(always)]
+fn ct_3layers(r: &mut [i16; 256], base: usize, stride: usize, k: [usize; 3]) {
+    let mut a = [0u32; 8];
+    for (m, w) in a.iter_mut().enumerate() {
+        *w = load(r, base + m * stride);
+    }
+
+    let (lo, hi) = a.split_at_mut(4);
+    for i in 0..4 {
+        ct_butterfly(&mut lo[i], &mut hi[i], zeta(k[0]));
+    }
+    for (half, pair) in a.chunks_exact_mut(4).enumerate() {
+        let (lo, hi) = pair.split_at_mut(2);
+        for i in 0..2 {
+            ct_butterfly(&mut lo[i], &mut hi[i], zeta(k[1] + half));
+        }
+    }
+    for (quarter, pair) in a.chunks_exact_mut(2).enumerate() {
+        let (lo, hi) = pair.split_at_mut(1);
+        ct_butterfly(&mut lo[0], &mut hi[0], zeta(k[2] + quarter));
+    }
+
+    for (m, w) in a.iter().enumerate() {
+        store(r, base + m * stride, *w);
+    }
+}
+
+/// Three merged inverse layers on the eight words `base + m * stride`, the
+/// mirror of [`ct_3layers`]: `k` lists the first zeta index of each layer,
+/// indices go down within a layer as in the reference.
+#[inline(always)]
+fn gs_3layers(r: &mut [i16; 256], base: usize, stride: usize, k: [usize; 3]) {
+    let mut a = [0u32; 8];
+    for (m, w) in a.iter_mut().enumerate() {
+        *w = load(r, base + m * stride);
+    }
+
+    for (quarter, pair) in a.chunks_exact_mut(2).enumerate() {
+        let (lo, hi) = pair.split_at_mut(1);
+        gs_butterfly(&mut lo[0], &mut hi[0], zeta(k[0] - quarter));
+    }
+    for (half, pair) in a.chunks_exact_mut(4).enumerate() {
+        let (lo, hi) = pair.split_at_mut(2);
+        for i in 0..2 {
+            gs_butterfly(&mut lo[i], &mut hi[i], zeta(k[1] - half));
+        }
+    }
+    let (lo, hi) = a.split_at_mut(4);
+    for i in 0..4 {
+        gs_butterfly(&mut lo[i], &mut hi[i], zeta(k[2]));
+    }
+
+    for (m, w) in a.iter().enumerate() {
+        store(r, base + m * stride, *w);
+    }
+}
+
+/// Forward NTT, drop-in for `pqc_kyber::ntt`.
+pub fn ntt(r: &mut [i16; 256]) {
+    // layers 1-3 (len 128, 64, 32): words w, w + 16, ..., w + 112
+    for w in 0..16 {
+        ct_3layers(r, w, 16, [1, 2, 4]);
+    }
+    // layers 4-6 (len 16, 8, 4): 16 words per block, words b + w, b + w + 2, ...
+    for block in 0..8 {
+        for w in 0..2 {
+            ct_3layers(r, 16 * block + w, 2, [8 + block, 16 + 2 * block, 32 + 4 * block]);
+        }
+    }
+    // layer 7 (len 2): one butterfly between neighbouring words
+    for i in 0..64 {
+        let mut a0 = load(r, 2 * i);
+        let mut a1 = load(r, 2 * i + 1);
+        ct_butterfly(&mut a0, &mut a1, zeta(64 + i));
+        store(r, 2 * i, a0);
+        store(r, 2 * i + 1, a1);
+    }
+}
+
+/// Inverse NTT including the final multiplication by mont^2/128, drop-in for
+/// `pqc_kyber::invntt`.
+pub fn invntt(r: &mut [i16; 256]) {
+    // layer 7 (len 2)
+    for i in 0..64 {
+        let mut a0 = load(r, 2 * i);
+        let mut a1 = load(r, 2 * i + 1);
+        gs_butterfly(&mut a0, &mut a1, zeta(127 - i));
+        store(r, 2 * i, a0);
+        store(r, 2 * i + 1, a1);
+    }
+    // layers 6-4 (len 4, 8, 16)
+    for block in 0..8 {
+        for w in 0..2 {
+            gs_3layers(r, 16 * block + w, 2, [63 - 4 * block, 31 - 2 * block, 15 - block]);
+        }
+    }
+    // layers 3-1 (len 32, 64, 128)
+    for w in 0..16 {
+        gs_3layers(r, w, 16, [7, 3, 1]);
+    }
+    for word in 0..128 {
+        let w = load(r, word);
+        store(r, word, fqmul2(w, INVNTT_F));
+    }
+}
+
+/// Multiplication in the NTT domain, drop-in for `pqc_kyber::poly_basemul` on
+/// the coefficient arrays (`poly::poly_basemul`).
+pub fn basemul(r: &mut [i16; 256], a: &[i16; 256], b: &[i16; 256]) {
+    for i in 0..64 {
+        let z = ZETAS[64 + i];
+        for (word, zeta) in [(2 * i, z), (2 * i + 1, z.wrapping_neg())].iter() {
+            let a = load(a, *word);
+            let b = load(b, *word);
+            let zeta = *zeta as u16 as u32;
+            // r0 = fqmul(fqmul(a1, b1), zeta) + fqmul(a0, b0)
+            let r0 = montgomery(smultb(montgomery(smultt(a, b)), zeta));
+            let r0 = sadd16(r0, montgomery(smulbb(a, b)));
+            // r1 = fqmul(a0, b1) + fqmul(a1, b0)
+            let r1 = sadd16(montgomery(smulbt(a, b)), montgomery(smultb(a, b)));
+            store(r, *word, pkhtb(r1, r0));
+        }
+    }
+}
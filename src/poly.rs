@@ -0,0 +1,17 @@
+//! Operations on bare `[i16; 256]` coefficient arrays, the shape the NTT
+//! backends of this crate work on, where pqc_kyber only offers them on its
+//! private `Poly`.
+
+use crate::ntt_asm::ZETAS;
+
+/// `poly_basemul_montgomery` on top of `pqc_kyber::basemul`: the 64 pairs of
+/// degree-one products of two polynomials in the NTT domain, with `ZETAS[64 + i]`
+/// and its negation. The reference the other base multiplications are
+/// checked and timed against.
+pub fn poly_basemul(r: &mut [i16; 256], a: &[i16; 256], b: &[i16; 256]) {
+    for i in 0..64 {
+        let zeta = ZETAS[64 + i];
+        pqc_kyber::basemul(&mut r[4 * i..], &a[4 * i..], &b[4 * i..], zeta);
+        pqc_kyber::basemul(&mut r[4 * i + 2..], &a[4 * i + 2..], &b[4 * i + 2..], -zeta);
+    }
+}
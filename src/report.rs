@@ -0,0 +1,190 @@
+//! Machine-readable benchmark results, written to a file on the host through
+//! semihosting (relative to the working directory of OpenOCD or QEMU), or
+//! with `std::fs` in a host build.
+//!
+//! Every record carries the parameter set, the operation, the counter, the
+//! clock profile, the opt-level and the summary statistics, one JSON object
+//! or CSV row per [`Stats`].
+
+use core::fmt::{self, Write};
+
+#[cfg(not(feature = "std"))]
+use cortex_m_semihosting::{nr::open, syscall};
+
+use crate::bench::Stats;
+use crate::clock::ClockProfile;
+use crate::{OPT_LEVEL, PARAMETER_SET};
+
+/// Longest file name [`Sink::create`] accepts on the target.
+#[cfg(not(feature = "std"))]
+const MAX_PATH: usize = 64;
+
+const CSV_HEADER: &str = "parameter_set,operation,counter,clock,sysclk_hz,opt_level,iterations,min,max,mean,median,p90,p99,stddev,stack";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One array of objects.
+    Json,
+    /// A header line and one row per record.
+    Csv,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The name does not fit in the semihosting buffer of `MAX_PATH` bytes.
+    PathTooLong,
+    /// The host refused to open the file (or no debugger is attached).
+    Open,
+    Write,
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Write
+    }
+}
+
+/// `std::fs::File` as a [`fmt::Write`], like the semihosting stream.
+#[cfg(feature = "std")]
+struct Stream(std::fs::File);
+
+#[cfg(feature = "std")]
+impl Write for Stream {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        std::io::Write::write_all(&mut self.0, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+/// Semihosting file handle; `hio::HostStream` only reaches the console.
+#[cfg(not(feature = "std"))]
+struct Stream {
+    fd: usize,
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for Stream {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut buffer = s.as_bytes();
+        while !buffer.is_empty() {
+            // SYS_WRITE returns the number of bytes left unwritten
+            match unsafe { syscall!(WRITE, self.fd, buffer.as_ptr(), buffer.len()) } {
+                0 => return Ok(()),
+                // nothing written, retrying would loop forever
+                n if n == buffer.len() => return Err(fmt::Error),
+                n if n < buffer.len() => buffer = &buffer[buffer.len() - n..],
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Drop for Stream {
+    fn drop(&mut self) {
+        unsafe { syscall!(CLOSE, self.fd) };
+    }
+}
+
+#[cfg(not(feature = "std"))]
+fn create_file(path: &str) -> Result<Stream, Error> {
+    // semihosting wants a NUL terminated name
+    let mut name = [0u8; MAX_PATH];
+    if path.len() >= MAX_PATH {
+        return Err(Error::PathTooLong);
+    }
+    name[..path.len()].copy_from_slice(path.as_bytes());
+    match unsafe { syscall!(OPEN, name.as_ptr(), open::W_TRUNC, path.len()) } as isize {
+        -1 => Err(Error::Open),
+        fd => Ok(Stream { fd: fd as usize }),
+    }
+}
+
+#[cfg(feature = "std")]
+fn create_file(path: &str) -> Result<Stream, Error> {
+    std::fs::File::create(path).map(Stream).map_err(|_| Error::Open)
+}
+
+pub struct Sink {
+    stream: Stream,
+    format: Format,
+    clock: ClockProfile,
+    records: usize,
+}
+
+impl Sink {
+    /// Creates (or truncates) `path` on the host.
+    pub fn create(path: &str, format: Format, clock: ClockProfile) -> Result<Sink, Error> {
+        let stream = create_file(path)?;
+        let mut sink = Sink { stream, format, clock, records: 0 };
+        match format {
+            Format::Json => sink.stream.write_str("[")?,
+            Format::Csv => writeln!(sink.stream, "{}", CSV_HEADER)?,
+        }
+        Ok(sink)
+    }
+
+    /// Appends one record per entry of `stats`, measured with `counter`.
+    pub fn record(&mut self, counter: &str, stats: &[Stats]) -> Result<(), Error> {
+        for s in stats {
+            match self.format {
+                Format::Json => self.json(counter, s)?,
+                Format::Csv => self.csv(counter, s)?,
+            }
+            self.records += 1;
+        }
+        Ok(())
+    }
+
+    /// Closes the JSON array; the CSV needs nothing more.
+    pub fn finish(mut self) -> Result<(), Error> {
+        if self.format == Format::Json {
+            self.stream.write_str("\n]\n")?;
+        }
+        Ok(())
+    }
+
+    fn json(&mut self, counter: &str, s: &Stats) -> fmt::Result {
+        let separator = if self.records == 0 { "" } else { "," };
+        write!(
+            self.stream,
+            "{}\n  {{\"parameter_set\": \"{}\", \"operation\": \"{}\", \"counter\": \"{}\", \
+             \"clock\": \"{}\", \"sysclk_hz\": {}, \"opt_level\": \"{}\", \"iterations\": {}, ",
+            separator, PARAMETER_SET, s.name, counter, self.clock.name, self.clock.sysclk_hz, OPT_LEVEL, s.iterations
+        )?;
+        write!(
+            self.stream,
+            "\"min\": {}, \"max\": {}, \"mean\": {}, \"median\": {}, \"p90\": {}, \"p99\": {}, \"stddev\": {}, \"stack\": ",
+            s.min, s.max, s.mean, s.median, s.p90, s.p99, s.stddev
+        )?;
+        match s.stack {
+            Some(bytes) => write!(self.stream, "{}}}", bytes),
+            None => self.stream.write_str("null}"),
+        }
+    }
+
+    fn csv(&mut self, counter: &str, s: &Stats) -> fmt::Result {
+        write!(
+            self.stream,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},",
+            PARAMETER_SET,
+            s.name,
+            counter,
+            self.clock.name,
+            self.clock.sysclk_hz,
+            OPT_LEVEL,
+            s.iterations,
+            s.min,
+            s.max,
+            s.mean,
+            s.median,
+            s.p90,
+            s.p99,
+            s.stddev
+        )?;
+        match s.stack {
+            Some(bytes) => writeln!(self.stream, "{}", bytes),
+            None => writeln!(self.stream),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Random number generators handed to pqc_kyber.
+//!
+//! [`HwRng`] wraps the STM32F4 RNG peripheral: every word goes through the
+//! clock-error and seed-error checks of the HAL and through the continuous
+//! test of RM0090 (24.3.1), which rejects a word equal to the previous one.
+//! [`SeededRng`] is the deterministic fallback for QEMU and host builds.
+
+#[cfg(feature = "stm32f407")]
+use core::num::NonZeroU32;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_core::{CryptoRng, Error, RngCore};
+
+/// Error code of two consecutive equal words from the hardware RNG.
+pub const REPEATED_OUTPUT: u32 = Error::CUSTOM_START + 0x10;
+
+/// Seed of [`SeededRng::default`].
+pub const DEFAULT_SEED: [u8; 32] = [0u8; 32];
+
+/// Hardware RNG with health checks; any failed check is an error, never a
+/// silently weaker output.
+#[cfg(feature = "stm32f407")]
+pub struct HwRng {
+    rng: stm32f4xx_hal::rng::Rng,
+    /// Previous word, for the continuous test.
+    last: u32,
+}
+
+#[cfg(feature = "stm32f407")]
+impl HwRng {
+    /// Takes over the RNG enabled by `RNG.constrain(&clocks)` and draws the
+    /// first word, which is only kept for the continuous test.
+    pub fn new(mut rng: stm32f4xx_hal::rng::Rng) -> Result<HwRng, Error> {
+        let last = read_word(&mut rng)?;
+        Ok(HwRng { rng, last })
+    }
+
+    /// Next checked word: `CECS` and `SECS` are checked by the HAL.
+    pub fn try_next_u32(&mut self) -> Result<u32, Error> {
+        let word = read_word(&mut self.rng)?;
+        if word == self.last {
+            return Err(NonZeroU32::new(REPEATED_OUTPUT).unwrap().into());
+        }
+        self.last = word;
+        Ok(word)
+    }
+}
+
+/// One word from the peripheral; the HAL turns `CECS` and `SECS` into an
+/// [`Error`].
+#[cfg(feature = "stm32f407")]
+fn read_word(rng: &mut stm32f4xx_hal::rng::Rng) -> Result<u32, Error> {
+    let mut word = [0u8; 4];
+    RngCore::try_fill_bytes(rng, &mut word)?;
+    Ok(u32::from_le_bytes(word))
+}
+
+#[cfg(feature = "stm32f407")]
+impl RngCore for HwRng {
+    fn next_u32(&mut self) -> u32 {
+        self.try_next_u32().expect("hardware RNG health check failed")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("hardware RNG health check failed")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(4) {
+            let word = self.try_next_u32()?.to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+/// The RNG peripheral is a true RNG, it meets the requirements of pqc_kyber
+/// as long as the checks above pass.
+#[cfg(feature = "stm32f407")]
+impl CryptoRng for HwRng {}
+
+/// ChaCha12 (`StdRng`) from a fixed seed: reproducible runs where there is no
+/// entropy source. Not for keys that matter.
+pub struct SeededRng(StdRng);
+
+impl SeededRng {
+    pub fn new(seed: [u8; 32]) -> SeededRng {
+        SeededRng(StdRng::from_seed(seed))
+    }
+}
+
+impl Default for SeededRng {
+    fn default() -> Self {
+        SeededRng::new(DEFAULT_SEED)
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for SeededRng {}
@@ -0,0 +1,72 @@
+//! Stack usage measurement by stack painting.
+//!
+//! The free stack, from the end of `.bss`/`.uninit` (`__sheap`, provided by
+//! cortex-m-rt), or of the `.ccram` statics with the `ccram-stack` feature, up
+//! to the stack pointer, is filled with [`CANARY`]. After running the
+//! operation the lowest overwritten word gives the high-water mark.
+
+/// Pattern written to every free stack word.
+pub const CANARY: u32 = 0xCCCC_CCCC;
+
+#[cfg(not(feature = "ccram-stack"))]
+extern "C" {
+    static mut __sheap: u32;
+}
+
+#[cfg(not(feature = "ccram-stack"))]
+fn stack_bottom() -> usize {
+    core::ptr::addr_of_mut!(__sheap) as usize
+}
+
+#[cfg(feature = "ccram-stack")]
+fn stack_bottom() -> usize {
+    crate::ccram::end()
+}
+
+/// Paints the free stack with [`CANARY`] up to the current stack pointer and
+/// returns that stack pointer, which the measurement is relative to.
+///
+/// The loop only uses registers, so everything below the stack pointer is
+/// free and painted, and inlined into the caller it leaves no frame of its
+/// own below it.
+#[inline(always)]
+pub fn paint() -> usize {
+    let bottom = (stack_bottom() + 3) & !3;
+    let sp: usize;
+    unsafe {
+        core::arch::asm!(
+            "mov {sp}, sp",
+            "2:",
+            "cmp {p}, {sp}",
+            "bhs 3f",
+            "str {canary}, [{p}], #4",
+            "b 2b",
+            "3:",
+            sp = out(reg) sp,
+            p = inout(reg) bottom => _,
+            canary = in(reg) CANARY,
+            options(nostack),
+        )
+    };
+    sp
+}
+
+/// Bytes of stack used below `sp` since the last [`paint`].
+#[inline(never)]
+pub fn high_water(sp: usize) -> usize {
+    let mut p = (stack_bottom() + 3) & !3;
+    while p < sp && unsafe { (p as *const u32).read_volatile() } == CANARY {
+        p += 4;
+    }
+    sp - p
+}
+
+/// Runs `f` on a freshly painted stack and returns its result together with
+/// the peak stack usage in bytes, counted from the stack pointer `f` is
+/// called with.
+#[inline(always)]
+pub fn measure<R, F: FnOnce() -> R>(f: F) -> (R, usize) {
+    let sp = paint();
+    let r = f();
+    (r, high_water(sp))
+}
@@ -0,0 +1,117 @@
+//! The KEM and NTT benchmarks of `speed_evaluation`, shared by the firmware
+//! and the host build (`host_speed_evaluation`) so both time the same work.
+
+use pqc_kyber::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair, KYBER_CIPHERTEXTBYTES, KYBER_PUBLICKEYBYTES, KYBER_SECRETKEYBYTES, KYBER_SSBYTES};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::bench::{bench, Stats};
+use crate::counter::CycleCounter;
+use crate::exit;
+#[cfg(feature = "m4-dsp-ntt")]
+use crate::{ntt_m4, poly::poly_basemul};
+#[cfg(feature = "m4-dsp-ntt")]
+use pqc_kyber::invntt;
+
+pub const ITERATIONS: usize = 1_000;
+pub const WARMUP: usize = 10;
+
+/// Deterministic coins for `crypto_kem_keypair` and `crypto_kem_enc`, so every
+/// iteration does the same work.
+const KEYPAIR_SEED: ([u8; 32], [u8; 32]) = ([1u8; 32], [255u8; 32]);
+const ENCAPS_SEED: [u8; 32] = [255u8; 32];
+
+/// Runs keypair, encapsulation and decapsulation back to back, each one on the
+/// output of the previous, and checks that both sides agree on the secret.
+/// After the timed runs every operation is handed once more to `stack`, which
+/// returns its peak stack usage where it can be measured.
+pub fn kem<C, R, S>(counter: &mut C, rng: &mut R, mut stack: S) -> [Stats; 3]
+where
+    C: CycleCounter,
+    R: RngCore + CryptoRng,
+    S: FnMut(&mut dyn FnMut()) -> Option<usize>,
+{
+    let mut pk = [0u8; KYBER_PUBLICKEYBYTES];
+    let mut sk = [0u8; KYBER_SECRETKEYBYTES];
+    let mut keypair_op = || {
+        let bufs = Some((KEYPAIR_SEED.0.as_slice(), KEYPAIR_SEED.1.as_slice()));
+        crypto_kem_keypair(&mut pk, &mut sk, rng, bufs)
+    };
+    // checked once outside the timed runs, which discard the results
+    exit::check("crypto_kem_keypair", keypair_op());
+    let keypair = bench(counter, "crypto_kem_keypair", ITERATIONS, WARMUP, &mut keypair_op);
+    let keypair_stack = stack(&mut || exit::check("crypto_kem_keypair", keypair_op()));
+
+    let mut ct = [0u8; KYBER_CIPHERTEXTBYTES];
+    let mut ss_enc = [0u8; KYBER_SSBYTES];
+    let mut enc_op = || crypto_kem_enc(&mut ct, &mut ss_enc, &pk, rng, Some(ENCAPS_SEED.as_slice()));
+    exit::check("crypto_kem_enc", enc_op());
+    let enc = bench(counter, "crypto_kem_enc", ITERATIONS, WARMUP, &mut enc_op);
+    let enc_stack = stack(&mut || exit::check("crypto_kem_enc", enc_op()));
+
+    let mut ss_dec = [0u8; KYBER_SSBYTES];
+    let mut dec_op = || crypto_kem_dec(&mut ss_dec, &ct, &sk);
+    let dec = bench(counter, "crypto_kem_dec", ITERATIONS, WARMUP, &mut dec_op);
+    let dec_stack = stack(&mut dec_op);
+
+    assert_eq!(ss_enc, ss_dec, "shared secrets do not match");
+    [
+        Stats { stack: keypair_stack, ..keypair },
+        Stats { stack: enc_stack, ..enc },
+        Stats { stack: dec_stack, ..dec },
+    ]
+}
+
+/// Times the `ntt_m4` backend next to the pqc_kyber functions it replaces, on
+/// the same input, and checks that both give the same polynomial.
+#[cfg(feature = "m4-dsp-ntt")]
+pub fn ntt<C: CycleCounter>(counter: &mut C) -> [Stats; 6] {
+    let mut a = [0i16; 256];
+    let mut b = [0i16; 256];
+    for (i, (a, b)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+        *a = (i as i16 * 13) % 3329 - 1664;
+        *b = (i as i16 * 29) % 3329 - 1664;
+    }
+
+    let ref_ntt = || {
+        let mut r = a;
+        pqc_kyber::ntt(&mut r);
+        r
+    };
+    let m4_ntt = || {
+        let mut r = a;
+        ntt_m4::ntt(&mut r);
+        r
+    };
+    let ref_invntt = || {
+        let mut r = a;
+        invntt(&mut r);
+        r
+    };
+    let m4_invntt = || {
+        let mut r = a;
+        ntt_m4::invntt(&mut r);
+        r
+    };
+    let ref_basemul = || {
+        let mut r = [0i16; 256];
+        poly_basemul(&mut r, &a, &b);
+        r
+    };
+    let m4_basemul = || {
+        let mut r = [0i16; 256];
+        ntt_m4::basemul(&mut r, &a, &b);
+        r
+    };
+
+    assert!(ref_ntt() == m4_ntt(), "ntt_m4::ntt differs from pqc_kyber");
+    assert!(ref_invntt() == m4_invntt(), "ntt_m4::invntt differs from pqc_kyber");
+    assert!(ref_basemul() == m4_basemul(), "ntt_m4::basemul differs from pqc_kyber");
+    [
+        bench(counter, "ntt (pqc_kyber)", ITERATIONS, WARMUP, ref_ntt),
+        bench(counter, "ntt (m4)", ITERATIONS, WARMUP, m4_ntt),
+        bench(counter, "invntt (pqc_kyber)", ITERATIONS, WARMUP, ref_invntt),
+        bench(counter, "invntt (m4)", ITERATIONS, WARMUP, m4_invntt),
+        bench(counter, "basemul (pqc_kyber)", ITERATIONS, WARMUP, ref_basemul),
+        bench(counter, "basemul (m4)", ITERATIONS, WARMUP, m4_basemul),
+    ]
+}
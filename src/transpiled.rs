@@ -0,0 +1,46 @@
+//! FFI bindings to the C translations of the NTT in `transpilation/`.
+//!
+//! The translated files are not built, only hand-trimmed excerpts of them:
+//! `build.rs` compiles the callable versions in `transpilation/ffi/` for host
+//! targets only. They are the first function of `rust_ntt_to_c.c` and the
+//! "ALTERNATIVE" one of `ntt_asm_to_c.c`. The rest of those files is not
+//! valid C, see the headers of the excerpts. The translations read and write outside a 256 coefficient
+//! polynomial, so the wrappers run them on a padded scratch buffer and copy
+//! the first 256 halfwords back.
+
+use crate::ntt_asm::ZETAS;
+
+/// Halfwords of padding on both sides of the scratch buffer.
+const PAD: usize = 16;
+
+/// The translations treat the `.Lanon` stack buffer as 512 halfwords.
+const BUFFER: usize = 512;
+
+extern "C" {
+    fn rust_ntt_to_c(buffer: *mut u16, coefficients: *const i16);
+    fn ntt_asm_to_c(r: *mut u16);
+}
+
+fn on_scratch<F: FnOnce(*mut u16)>(r: &mut [i16; 256], f: F) {
+    let mut scratch = [0u16; PAD + BUFFER + PAD];
+    for (s, c) in scratch[PAD..].iter_mut().zip(r.iter()) {
+        *s = *c as u16;
+    }
+    f(scratch[PAD..].as_mut_ptr());
+    for (c, s) in r.iter_mut().zip(scratch[PAD..].iter()) {
+        *c = *s as i16;
+    }
+}
+
+/// `transpilation/rust_ntt_to_c.c`, first translation.
+pub fn ntt_rust_to_c(r: &mut [i16; 256]) {
+    // the translation reads 256 coefficients from the zetas table
+    let mut coefficients = [0i16; 256];
+    coefficients[..ZETAS.len()].copy_from_slice(&ZETAS);
+    on_scratch(r, |buffer| unsafe { rust_ntt_to_c(buffer, coefficients.as_ptr()) });
+}
+
+/// `transpilation/ntt_asm_to_c.c`, "ALTERNATIVE" translation.
+pub fn ntt_asm_to_c_alt(r: &mut [i16; 256]) {
+    on_scratch(r, |buffer| unsafe { ntt_asm_to_c(buffer) });
+}
@@ -0,0 +1,15 @@
+//! Fixtures shared by the NTT tests (and `benches/ntt.rs`).
+
+use pqc_kyber::KYBER_Q;
+use rand::rngs::SmallRng;
+use rand::Rng;
+
+/// Coefficients bounded by q in absolute value, as `poly_ntt` expects.
+pub fn polynomial(rng: &mut SmallRng) -> [i16; 256] {
+    let q = KYBER_Q as i16;
+    let mut r = [0i16; 256];
+    for c in r.iter_mut() {
+        *c = rng.gen_range(-q + 1..q);
+    }
+    r
+}
@@ -0,0 +1,33 @@
+//! The online Welch's t-test on synthetic timings.
+//!
+//! Host only: `cargo test --target x86_64-unknown-linux-gnu --no-default-features --features std --test leakage`
+
+use kyber_rust::leakage::WelchTest;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const MEASUREMENTS: usize = 100_000;
+
+fn welch(shift: u64) -> WelchTest {
+    let mut rng = SmallRng::seed_from_u64(0x7465_7374);
+    let mut test = WelchTest::new();
+    for _ in 0..MEASUREMENTS {
+        let class = rng.gen_range(0..2);
+        let cycles = 10_000 + rng.gen_range(0..100) + class as u64 * shift;
+        test.push(class, cycles);
+    }
+    test
+}
+
+#[test]
+fn same_distribution_does_not_leak() {
+    let test = welch(0);
+    assert!(!test.leaks(), "t = {}", test.t());
+}
+
+#[test]
+fn shifted_distribution_leaks() {
+    let test = welch(2);
+    assert!(test.leaks(), "t = {}", test.t());
+    assert!(test.t() < 0.0, "class 1 is slower, t = {}", test.t());
+}
@@ -0,0 +1,29 @@
+//! Differential test of `ntt_from_asm`, the NTT re-implemented from the
+//! listing, against `pqc_kyber::ntt`.
+//!
+//! Host only: `cargo test --target x86_64-unknown-linux-gnu --no-default-features --features std --test ntt_from_asm`
+
+mod common;
+
+use common::polynomial;
+use kyber_rust::ntt_asm::ntt_from_asm;
+use pqc_kyber::ntt;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+const POLYNOMIALS: usize = 10_000;
+
+#[test]
+fn ntt_from_asm_matches_pqc_kyber() {
+    let mut rng = SmallRng::seed_from_u64(0x6b_7962_6572);
+    for i in 0..POLYNOMIALS {
+        let input = polynomial(&mut rng);
+        let mut expected = input;
+        let mut actual = input;
+
+        ntt(&mut expected);
+        ntt_from_asm(&mut actual);
+
+        assert_eq!(&expected[..], &actual[..], "polynomial {} differs, input {:?}", i, &input[..]);
+    }
+}
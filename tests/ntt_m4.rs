@@ -0,0 +1,63 @@
+//! Differential test of the DSP intrinsics `ntt_m4` backend against
+//! pqc_kyber. Off target the DSP instructions are computed in Rust, so this
+//! checks the merged layers and the reductions, not the `asm!` encodings.
+//!
+//! Host only: `cargo test --target x86_64-unknown-linux-gnu --no-default-features --features std,m4-dsp-ntt --test ntt_m4`
+#![cfg(feature = "m4-dsp-ntt")]
+
+mod common;
+
+use common::polynomial;
+use kyber_rust::poly::poly_basemul;
+use kyber_rust::ntt_m4;
+use pqc_kyber::{invntt, ntt};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+const POLYNOMIALS: usize = 10_000;
+
+#[test]
+fn ntt_matches_pqc_kyber() {
+    let mut rng = SmallRng::seed_from_u64(0x6d_346e_7474);
+    for i in 0..POLYNOMIALS {
+        let input = polynomial(&mut rng);
+        let mut expected = input;
+        let mut actual = input;
+
+        ntt(&mut expected);
+        ntt_m4::ntt(&mut actual);
+
+        assert_eq!(&expected[..], &actual[..], "polynomial {} differs, input {:?}", i, &input[..]);
+    }
+}
+
+#[test]
+fn invntt_matches_pqc_kyber() {
+    let mut rng = SmallRng::seed_from_u64(0x6d_3469_6e76);
+    for i in 0..POLYNOMIALS {
+        let input = polynomial(&mut rng);
+        let mut expected = input;
+        let mut actual = input;
+
+        invntt(&mut expected);
+        ntt_m4::invntt(&mut actual);
+
+        assert_eq!(&expected[..], &actual[..], "polynomial {} differs, input {:?}", i, &input[..]);
+    }
+}
+
+#[test]
+fn basemul_matches_pqc_kyber() {
+    let mut rng = SmallRng::seed_from_u64(0x6d_3462_6173);
+    for i in 0..POLYNOMIALS {
+        let a = polynomial(&mut rng);
+        let b = polynomial(&mut rng);
+
+        let mut expected = [0i16; 256];
+        poly_basemul(&mut expected, &a, &b);
+        let mut actual = [0i16; 256];
+        ntt_m4::basemul(&mut actual, &a, &b);
+
+        assert_eq!(&expected[..], &actual[..], "polynomials {} differ", i);
+    }
+}
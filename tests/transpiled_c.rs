@@ -0,0 +1,86 @@
+//! Randomized differential test of the C translations in `transpilation/`
+//! against `pqc_kyber::ntt`.
+//!
+//! Host only: `cargo test --target x86_64-unknown-linux-gnu --no-default-features --features std --test transpiled_c`
+//!
+//! Only the hand-trimmed excerpts in `transpilation/ffi/` are built, not the
+//! translated files themselves (see `kyber_rust::transpiled`).
+//!
+//! Every polynomial is drawn from its own seed, so a reported mismatch can be
+//! replayed with `polynomial(seed)`. Neither excerpt matches pqc_kyber: their
+//! tests assert the first divergence from it, i.e. the seed, the coefficient
+//! and both values.
+
+mod common;
+
+use kyber_rust::transpiled::{ntt_asm_to_c_alt, ntt_rust_to_c};
+use pqc_kyber::ntt;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+const POLYNOMIALS: u64 = 10_000;
+const FIRST_SEED: u64 = 0x4e54_5400;
+
+fn polynomial(seed: u64) -> [i16; 256] {
+    common::polynomial(&mut SmallRng::seed_from_u64(seed))
+}
+
+/// Where a candidate first disagrees with `pqc_kyber::ntt`.
+#[derive(Debug, PartialEq)]
+struct Divergence {
+    seed: u64,
+    /// First diverging coefficient.
+    index: usize,
+    actual: i16,
+    expected: i16,
+}
+
+/// The first polynomial, in seed order, on which `candidate` and
+/// `pqc_kyber::ntt` disagree.
+fn first_divergence(candidate: fn(&mut [i16; 256])) -> Option<Divergence> {
+    for seed in FIRST_SEED..FIRST_SEED + POLYNOMIALS {
+        let mut expected = polynomial(seed);
+        let mut actual = expected;
+        ntt(&mut expected);
+        candidate(&mut actual);
+
+        if let Some(index) = (0..256).find(|&i| expected[i] != actual[i]) {
+            return Some(Divergence {
+                seed,
+                index,
+                actual: actual[index],
+                expected: expected[index],
+            });
+        }
+    }
+    None
+}
+
+/// The translation is not faithful: its first layer pairs `r[l]` with
+/// `r[l + 256]`, past the polynomial, and the next three subtract and add the
+/// same value to one coefficient, so only its unrolled layers change the
+/// input.
+#[test]
+fn rust_ntt_to_c_diverges_from_pqc_kyber() {
+    let expected = Divergence {
+        seed: FIRST_SEED,
+        index: 0,
+        actual: 0,
+        expected: 2082,
+    };
+    assert_eq!(first_divergence(ntt_rust_to_c), Some(expected), "a faithful translation has no divergence");
+}
+
+/// The "ALTERNATIVE" translation is not faithful either: it runs the layers
+/// from len 1 up to 64 instead of 128 down to 2 and multiplies by the zetas
+/// modulo 2^16, without a Montgomery reduction.
+#[test]
+fn ntt_asm_to_c_diverges_from_pqc_kyber() {
+    let expected = Divergence {
+        seed: FIRST_SEED,
+        index: 0,
+        actual: 11400,
+        expected: 2082,
+    };
+    assert_eq!(first_divergence(ntt_asm_to_c_alt), Some(expected), "a faithful translation has no divergence");
+}
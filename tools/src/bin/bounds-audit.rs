@@ -0,0 +1,397 @@
+//! Lists the panic sites (`panic_bounds_check`, `panic`, slice index and
+//! unwrap failures) reachable from the NTT and KEM entry points of a firmware
+//! ELF, per function and with the source line from DWARF, and checks them
+//! against an allow-list.
+//!
+//! `bounds-audit [--allow FILE [--update]] [--root NAME]... ELF`
+//!
+//! The call graph follows direct calls and tail calls; calls through a
+//! register are not followed. The allow-list has one `MAX FUNCTION` entry per
+//! line, `#` starts a comment and a `*` in FUNCTION matches any run of
+//! characters. A function takes its limit from the first entry it matches, so
+//! a wildcard entry bounds each function on its own, not their sum; a function
+//! with panic sites and no entry is allowed none. The tool exits with 1 when a
+//! function has more sites than allowed, and points out entries that can be
+//! lowered and entries that match no reachable function; `--update` writes
+//! the current counts to FILE instead.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use kyber_tools::elf::{self, Symbol};
+use kyber_tools::listing;
+
+const DEFAULT_ROOTS: [&str; 7] = [
+    "*::crypto_kem_keypair",
+    "*::crypto_kem_enc",
+    "*::crypto_kem_dec",
+    "pqc_kyber::reference::ntt::ntt",
+    "pqc_kyber::reference::ntt::invntt",
+    "kyber_rust::ntt_m4::ntt",
+    "kyber_rust::ntt_m4::invntt",
+];
+
+/// Callees that end in a panic. They are sites, not followed.
+const PANICS: [&str; 5] = [
+    "core::panicking::",
+    "core::slice::index::",
+    "core::option::unwrap_failed",
+    "core::option::expect_failed",
+    "core::result::unwrap_failed",
+];
+
+/// Formatting and panic machinery, not followed.
+const OPAQUE: [&str; 3] = ["core::fmt::", "core::panicking::", "rust_begin_unwind"];
+
+struct Site {
+    addr: u64,
+    callee: String,
+    line: Option<String>,
+}
+
+/// What a function branches to outside itself.
+enum Call<'a> {
+    Function(&'a Symbol),
+    /// A panic site, not followed.
+    Panic(Site),
+}
+
+/// The functions reached from the roots and their panic sites.
+struct Reach {
+    /// By address, since the hash-free names of generic instances collide.
+    visited: BTreeSet<u64>,
+    /// Panic sites of every reachable function, none for most of them.
+    sites: BTreeMap<String, Vec<Site>>,
+}
+
+/// Allow-list entries in file order.
+type AllowList = Vec<(String, usize)>;
+
+/// The allow-list checked against the site count of every reachable function.
+#[derive(Debug, Default, PartialEq)]
+struct Audit {
+    /// `(function, sites, allowed)` of the functions over their limit.
+    exceeded: Vec<(String, usize, usize)>,
+    /// `(pattern, allowed, sites)` of the entries above the largest count of
+    /// the functions they apply to.
+    loose: Vec<(String, usize, usize)>,
+    /// Entries that apply to no reachable function, missing or matched by an
+    /// earlier entry first.
+    stale: Vec<String>,
+}
+
+fn usage() -> ! {
+    eprintln!("usage: bounds-audit [--allow FILE [--update]] [--root NAME]... ELF");
+    process::exit(2)
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn is_panic(name: &str) -> bool {
+    PANICS.iter().any(|p| name.starts_with(p))
+}
+
+/// `file:line` relative to the crate source, without the registry prefix.
+fn short_path(line: &str) -> &str {
+    match line.find("/registry/src/") {
+        Some(i) => {
+            let rest = &line[i + "/registry/src/".len()..];
+            rest.split_once('/').map_or(rest, |(_, path)| path)
+        }
+        None => line,
+    }
+}
+
+fn read_allow(path: &Path) -> AllowList {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| fail(format!("cannot read {}: {}", path.display(), e)));
+    let mut allow = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let entry = line
+            .split_once(char::is_whitespace)
+            .and_then(|(max, function)| Some((function.trim().to_string(), max.parse().ok()?)));
+        match entry {
+            Some(entry) => allow.push(entry),
+            None => fail(format!("{}:{}: expected `MAX FUNCTION`", path.display(), n + 1)),
+        }
+    }
+    allow
+}
+
+/// Index of the entry `function` takes its limit from.
+fn entry(allow: &AllowList, function: &str) -> Option<usize> {
+    allow.iter().position(|(pattern, _)| elf::glob(pattern, function))
+}
+
+fn allowed(allow: &AllowList, function: &str) -> usize {
+    entry(allow, function).map_or(0, |index| allow[index].1)
+}
+
+/// Checks `counts`, the panic sites of every reachable function (zero
+/// included), against `allow`, one function at a time.
+fn audit(allow: &AllowList, counts: &BTreeMap<String, usize>) -> Audit {
+    let mut audit = Audit::default();
+    // largest count among the functions of each entry, `None` without any
+    let mut largest: Vec<Option<usize>> = vec![None; allow.len()];
+    for (function, &count) in counts {
+        let max = allowed(allow, function);
+        if count > max {
+            audit.exceeded.push((function.clone(), count, max));
+        }
+        if let Some(index) = entry(allow, function) {
+            largest[index] = largest[index].max(Some(count));
+        }
+    }
+    for ((pattern, max), largest) in allow.iter().zip(largest) {
+        match largest {
+            None => audit.stale.push(pattern.clone()),
+            Some(count) if count < *max => audit.loose.push((pattern.clone(), *max, count)),
+            Some(_) => {}
+        }
+    }
+    audit
+}
+
+/// Breadth first over the call graph from `roots`; `calls` lists what a
+/// function branches to.
+fn walk<'a, F>(roots: Vec<&'a Symbol>, mut calls: F) -> Reach
+where
+    F: FnMut(&'a Symbol) -> Vec<Call<'a>>,
+{
+    let mut queue: VecDeque<&Symbol> = roots.into();
+    let mut reach = Reach {
+        visited: BTreeSet::new(),
+        sites: BTreeMap::new(),
+    };
+    while let Some(function) = queue.pop_front() {
+        if !reach.visited.insert(function.addr) || OPAQUE.iter().any(|p| function.short_name().starts_with(p)) {
+            continue;
+        }
+        let sites = reach.sites.entry(function.short_name().to_string()).or_default();
+        for call in calls(function) {
+            match call {
+                Call::Function(target) => queue.push_back(target),
+                Call::Panic(site) => sites.push(site),
+            }
+        }
+    }
+    reach
+}
+
+fn write_allow(path: &Path, sites: &BTreeMap<String, Vec<Site>>) {
+    let mut out = String::from("# panic sites allowed per function, see `cargo bounds-audit`\n");
+    for (function, sites) in sites {
+        writeln!(out, "{} {}", sites.len(), function).unwrap();
+    }
+    fs::write(path, out).unwrap_or_else(|e| fail(format!("cannot write {}: {}", path.display(), e)));
+}
+
+fn main() {
+    let mut allow_file = None;
+    let mut update = false;
+    let mut roots = Vec::new();
+    let mut elf = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--allow" => allow_file = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "--update" => update = true,
+            "--root" => roots.push(args.next().unwrap_or_else(|| usage())),
+            "-h" | "--help" => usage(),
+            _ if elf.is_none() => elf = Some(PathBuf::from(arg)),
+            _ => usage(),
+        }
+    }
+    let elf = elf.unwrap_or_else(|| usage());
+    if update && allow_file.is_none() {
+        usage();
+    }
+    if roots.is_empty() {
+        roots = DEFAULT_ROOTS.iter().map(|r| r.to_string()).collect();
+    }
+
+    let mut symbols = elf::symbols(&elf).unwrap_or_else(|e| fail(e.to_string()));
+    symbols.retain(|s| s.is_code() && s.size > 0);
+    let containing = |addr: u64| symbols.iter().find(|s| addr >= s.addr && addr < s.addr + s.size);
+
+    let mut starts = Vec::new();
+    for root in &roots {
+        let hits: Vec<&Symbol> = symbols.iter().filter(|s| elf::glob(root, s.short_name())).collect();
+        if hits.is_empty() {
+            eprintln!("{}: no `{}` (inlined, or not linked in)", elf.display(), root);
+        }
+        starts.extend(hits);
+    }
+
+    let reach = walk(starts, |function| {
+        let raw = elf::disassemble(&elf, function, &["-l"]).unwrap_or_else(|e| fail(e.to_string()));
+        let mut calls = Vec::new();
+        for i in listing::parse(&raw) {
+            let target = match i.branch_address() {
+                Some(addr) if addr < function.addr || addr >= function.addr + function.size => containing(addr),
+                _ => continue,
+            };
+            let callee = match (target, &i.target_symbol) {
+                (Some(s), _) => s.short_name().to_string(),
+                (None, Some(name)) => name.clone(),
+                (None, None) => continue,
+            };
+            if is_panic(&callee) {
+                calls.push(Call::Panic(Site {
+                    addr: i.addr,
+                    callee,
+                    line: i.line.clone(),
+                }));
+            } else if let Some(target) = target {
+                calls.push(Call::Function(target));
+            }
+        }
+        calls
+    });
+    let visited = reach.visited;
+    let mut sites = reach.sites;
+    let counts: BTreeMap<String, usize> = sites.iter().map(|(f, s)| (f.clone(), s.len())).collect();
+    sites.retain(|_, sites| !sites.is_empty());
+
+    println!("{} functions reachable from {}", visited.len(), roots.join(", "));
+    let allow = match &allow_file {
+        Some(path) if !update => read_allow(path),
+        _ => Vec::new(),
+    };
+    for (function, sites) in &sites {
+        let max = allowed(&allow, function);
+        let verdict = match &allow_file {
+            Some(_) if !update && sites.len() > max => format!(", {} allowed: EXCEEDED", max),
+            Some(_) if !update => format!(", {} allowed", max),
+            _ => String::new(),
+        };
+        println!("\n{} ({} site{}{})", function, sites.len(), if sites.len() == 1 { "" } else { "s" }, verdict);
+        for s in sites {
+            let line = s.line.as_deref().map_or("?", short_path);
+            println!("    {:#010x} {:<40} {}", s.addr, s.callee, line);
+        }
+    }
+    let total: usize = sites.values().map(Vec::len).sum();
+    println!("\n{} panic sites in {} functions", total, sites.len());
+
+    if let Some(path) = &allow_file {
+        if update {
+            write_allow(path, &sites);
+            println!("allow-list written to {}", path.display());
+        } else {
+            let audit = audit(&allow, &counts);
+            for (pattern, max, count) in &audit.loose {
+                println!("`{}` allows {} but has at most {}: the entry can be tightened", pattern, max, count);
+            }
+            for pattern in &audit.stale {
+                println!("`{}` matches no reachable function: the entry is stale", pattern);
+            }
+            if !audit.exceeded.is_empty() {
+                fail(format!("{} functions exceed {}", audit.exceeded.len(), path.display()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(addr: u64, name: &str) -> Symbol {
+        Symbol {
+            addr,
+            size: 4,
+            kind: 't',
+            name: name.to_string(),
+        }
+    }
+
+    fn panic_site(addr: u64) -> Call<'static> {
+        Call::Panic(Site {
+            addr,
+            callee: "core::panicking::panic_bounds_check".to_string(),
+            line: None,
+        })
+    }
+
+    fn allow_list(entries: &[(&str, usize)]) -> AllowList {
+        entries.iter().map(|(p, max)| (p.to_string(), *max)).collect()
+    }
+
+    fn counts(functions: &[(&str, usize)]) -> BTreeMap<String, usize> {
+        functions.iter().map(|(f, n)| (f.to_string(), *n)).collect()
+    }
+
+    /// `kem::enc` calls `poly::ntt` twice and `poly::reduce`, which calls
+    /// `kem::enc` back; `poly::unused` is not reached.
+    #[test]
+    fn walk_follows_calls_once_and_collects_sites() {
+        let enc = symbol(0x100, "kem::enc::h0123456789abcdef");
+        let ntt = symbol(0x200, "poly::ntt");
+        let reduce = symbol(0x300, "poly::reduce");
+        let unused = symbol(0x400, "poly::unused");
+        let fmt = symbol(0x500, "core::fmt::write");
+        let reach = walk(vec![&enc], |function| match function.addr {
+            0x100 => vec![Call::Function(&ntt), panic_site(0x104), Call::Function(&ntt), Call::Function(&reduce)],
+            0x200 => vec![panic_site(0x202), panic_site(0x204), Call::Function(&fmt)],
+            0x300 => vec![Call::Function(&enc)],
+            0x400 => vec![Call::Function(&unused)],
+            0x500 => panic!("opaque functions are not disassembled"),
+            _ => unreachable!(),
+        });
+        assert_eq!(reach.visited.into_iter().collect::<Vec<_>>(), [0x100, 0x200, 0x300, 0x500]);
+        let sites: Vec<(&str, usize)> = reach.sites.iter().map(|(f, s)| (f.as_str(), s.len())).collect();
+        assert_eq!(sites, [("kem::enc", 1), ("poly::ntt", 2), ("poly::reduce", 0)]);
+    }
+
+    #[test]
+    fn first_matching_entry_wins() {
+        let allow = allow_list(&[("poly::ntt", 1), ("poly::*", 3), ("*", 5)]);
+        assert_eq!(allowed(&allow, "poly::ntt"), 1);
+        assert_eq!(allowed(&allow, "poly::invntt"), 3);
+        assert_eq!(allowed(&allow, "kem::enc"), 5);
+        assert_eq!(allowed(&allow_list(&[("poly::*", 3)]), "kem::enc"), 0);
+    }
+
+    #[test]
+    fn wildcard_entries_bound_each_function() {
+        let allow = allow_list(&[("poly::*", 2)]);
+        // 2 + 2 sites: within the limit of each function, no hint either
+        assert_eq!(audit(&allow, &counts(&[("poly::ntt", 2), ("poly::invntt", 2)])), Audit::default());
+        let audit = audit(&allow, &counts(&[("poly::ntt", 3), ("poly::invntt", 0)]));
+        assert_eq!(audit.exceeded, [("poly::ntt".to_string(), 3, 2)]);
+        assert!(audit.loose.is_empty());
+    }
+
+    #[test]
+    fn unlisted_functions_are_allowed_none() {
+        let audit = audit(&allow_list(&[]), &counts(&[("kem::enc", 1), ("poly::reduce", 0)]));
+        assert_eq!(audit.exceeded, [("kem::enc".to_string(), 1, 0)]);
+    }
+
+    #[test]
+    fn loose_entries_take_the_largest_count() {
+        let allow = allow_list(&[("poly::*", 4), ("kem::enc", 1)]);
+        let audit = audit(&allow, &counts(&[("poly::ntt", 1), ("poly::invntt", 3), ("kem::enc", 0)]));
+        let loose = [("poly::*".to_string(), 4, 3), ("kem::enc".to_string(), 1, 0)];
+        assert_eq!(audit, Audit { loose: loose.to_vec(), ..Audit::default() });
+    }
+
+    #[test]
+    fn entries_without_a_function_are_stale() {
+        let allow = allow_list(&[("poly::*", 2), ("poly::ntt", 1), ("kem::dec", 1)]);
+        let audit = audit(&allow, &counts(&[("poly::ntt", 2)]));
+        assert_eq!(audit.stale, ["poly::ntt", "kem::dec"]);
+        assert!(audit.exceeded.is_empty() && audit.loose.is_empty());
+    }
+}
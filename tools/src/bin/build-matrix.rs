@@ -0,0 +1,234 @@
+//! Builds a benchmark example for the QEMU board profile across opt-level,
+//! LTO and codegen-units settings, runs every build under QEMU and merges the
+//! cycles, stack usage and code size into one table.
+//!
+//! `build-matrix [--example NAME] [--features LIST] [--opt 0,1,2,3,s,z]
+//! [--lto off,on] [--cgu 1,16]`, from the root of the repository.
+//!
+//! QEMU runs with `-icount`, so SysTick follows the number of executed
+//! instructions rather than the host clock: the figures are reproducible
+//! and comparable between builds, not Cortex-M4 cycles. The example must
+//! write `<example>.csv` through `report::Sink`, as `speed_evaluation` does.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use kyber_tools::elf;
+
+const TARGET: &str = "thumbv7em-none-eabi";
+const MATRIX_DIR: &str = "target/matrix";
+
+/// `-icount` shift: 2^5 ns per instruction, SysTick (25 MHz) ticks 0.8 times
+/// per instruction.
+const ICOUNT_SHIFT: &str = "5";
+
+struct Profile {
+    opt: String,
+    lto: bool,
+    cgu: u32,
+}
+
+impl Profile {
+    fn name(&self) -> String {
+        format!("o{}-{}-cgu{}", self.opt, if self.lto { "lto" } else { "nolto" }, self.cgu)
+    }
+}
+
+/// One operation of one build.
+struct Measurement {
+    mean: u64,
+    stack: Option<u64>,
+}
+
+struct Run {
+    profile: Profile,
+    footprint: elf::Footprint,
+    /// By operation name, in the order of the report.
+    results: Vec<(String, Measurement)>,
+}
+
+fn usage() -> ! {
+    eprintln!("usage: build-matrix [--example NAME] [--features LIST] [--opt 0,1,2,3,s,z] [--lto off,on] [--cgu 1,16]");
+    process::exit(2)
+}
+
+fn list(arg: Option<String>) -> Vec<String> {
+    arg.unwrap_or_else(|| usage()).split(',').map(str::to_string).collect()
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn build(profile: &Profile, example: &str, features: &str, target_dir: &Path) -> PathBuf {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    let mut features_arg = String::from("qemu");
+    if !features.is_empty() {
+        features_arg.push(',');
+        features_arg.push_str(features);
+    }
+    let status = Command::new(cargo)
+        .args(["build", "--release", "--example", example, "--no-default-features", "--features"])
+        .arg(&features_arg)
+        .arg("--target-dir")
+        .arg(target_dir)
+        // config overrides the profile of Cargo.toml
+        .env("CARGO_PROFILE_RELEASE_OPT_LEVEL", &profile.opt)
+        .env("CARGO_PROFILE_RELEASE_LTO", if profile.lto { "fat" } else { "off" })
+        .env("CARGO_PROFILE_RELEASE_CODEGEN_UNITS", profile.cgu.to_string())
+        .status()
+        .unwrap_or_else(|e| fail(format!("cannot run cargo: {}", e)));
+    if !status.success() {
+        fail(format!("{}: build failed", profile.name()));
+    }
+    target_dir.join(TARGET).join("release").join("examples").join(example)
+}
+
+/// Runs `elf` under QEMU in `dir`, where the semihosting files end up.
+fn run_qemu(elf: &Path, dir: &Path) {
+    let qemu = env::var("QEMU").unwrap_or_else(|_| "qemu-system-arm".into());
+    let status = Command::new(&qemu)
+        .args(["-cpu", "cortex-m4", "-machine", "mps2-an386", "-nographic"])
+        .args(["-semihosting-config", "enable=on,target=native", "-icount"])
+        .arg(format!("shift={}", ICOUNT_SHIFT))
+        .arg("-kernel")
+        .arg(elf)
+        .current_dir(dir)
+        .status()
+        .unwrap_or_else(|e| fail(format!("cannot run {}: {}", qemu, e)));
+    if !status.success() {
+        fail(format!("{} exited with {}", elf.display(), status));
+    }
+}
+
+/// SysTick rows of the CSV written by `report::Sink`.
+fn read_csv(path: &Path) -> Vec<(String, Measurement)> {
+    let content = fs::read_to_string(path).unwrap_or_else(|e| fail(format!("cannot read {}: {}", path.display(), e)));
+    let mut lines = content.lines();
+    let header: Vec<&str> = lines.next().unwrap_or("").split(',').collect();
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| *h == name)
+            .unwrap_or_else(|| fail(format!("{}: no `{}` column", path.display(), name)))
+    };
+    let (operation, counter, mean, stack) = (column("operation"), column("counter"), column("mean"), column("stack"));
+    lines
+        .map(|line| line.split(',').collect::<Vec<_>>())
+        .filter(|fields| fields.get(counter) == Some(&"SysTick"))
+        .map(|fields| {
+            let measurement = Measurement {
+                mean: fields[mean].parse().unwrap_or(0),
+                stack: fields.get(stack).and_then(|s| s.parse().ok()),
+            };
+            (fields[operation].to_string(), measurement)
+        })
+        .collect()
+}
+
+impl Run {
+    fn get(&self, op: &str) -> Option<&Measurement> {
+        self.results.iter().find(|(o, _)| o == op).map(|(_, m)| m)
+    }
+}
+
+fn print_table(runs: &[Run], title: &str, value: impl Fn(&Run, &str) -> String) {
+    let operations: Vec<&String> = runs[0].results.iter().map(|(op, _)| op).collect();
+    println!("\n{}", title);
+    print!("{:<18} {:>8} {:>8}", "profile", "flash", "ram");
+    for op in &operations {
+        print!(" {:>20}", op);
+    }
+    println!();
+    for run in runs {
+        print!("{:<18} {:>8} {:>8}", run.profile.name(), run.footprint.flash, run.footprint.ram + run.footprint.ccram);
+        for op in &operations {
+            print!(" {:>20}", value(run, op));
+        }
+        println!();
+    }
+}
+
+fn write_csv(runs: &[Run], path: &Path) {
+    let mut csv = String::from("profile,opt_level,lto,codegen_units,flash,ram,operation,mean,stack\n");
+    for run in runs {
+        for (op, m) in &run.results {
+            let stack = m.stack.map(|s| s.to_string()).unwrap_or_default();
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{}\n",
+                run.profile.name(),
+                run.profile.opt,
+                run.profile.lto,
+                run.profile.cgu,
+                run.footprint.flash,
+                run.footprint.ram + run.footprint.ccram,
+                op,
+                m.mean,
+                stack
+            ));
+        }
+    }
+    fs::write(path, csv).unwrap_or_else(|e| fail(format!("cannot write {}: {}", path.display(), e)));
+}
+
+fn main() {
+    let mut example = String::from("speed_evaluation");
+    let mut features = String::new();
+    let mut opts = list(Some("0,1,2,3,s,z".into()));
+    let mut ltos = list(Some("off,on".into()));
+    let mut cgus = list(Some("1,16".into()));
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--example" => example = args.next().unwrap_or_else(|| usage()),
+            "--features" => features = args.next().unwrap_or_else(|| usage()),
+            "--opt" => opts = list(args.next()),
+            "--lto" => ltos = list(args.next()),
+            "--cgu" => cgus = list(args.next()),
+            _ => usage(),
+        }
+    }
+
+    let mut profiles = Vec::new();
+    for opt in &opts {
+        for lto in &ltos {
+            for cgu in &cgus {
+                let lto = match lto.as_str() {
+                    "on" => true,
+                    "off" => false,
+                    _ => usage(),
+                };
+                let cgu = cgu.parse().unwrap_or_else(|_| usage());
+                profiles.push(Profile { opt: opt.clone(), lto, cgu });
+            }
+        }
+    }
+
+    let mut runs = Vec::new();
+    for profile in profiles {
+        let dir = PathBuf::from(MATRIX_DIR).join(profile.name());
+        eprintln!("== {}", profile.name());
+        let elf = build(&profile, &example, &features, &dir);
+        run_qemu(&elf, &dir);
+        let footprint = elf::footprint(&elf).unwrap_or_else(|e| fail(e.to_string()));
+        let results = read_csv(&dir.join(format!("{}.csv", example)));
+        runs.push(Run { profile, footprint, results });
+    }
+    if runs.is_empty() || runs[0].results.is_empty() {
+        fail("no results".into());
+    }
+
+    print_table(&runs, "mean SysTick ticks (QEMU -icount)", |run, op| {
+        run.get(op).map(|m| m.mean.to_string()).unwrap_or_else(|| "-".into())
+    });
+    print_table(&runs, "peak stack bytes", |run, op| {
+        run.get(op).and_then(|m| m.stack).map(|s| s.to_string()).unwrap_or_else(|| "-".into())
+    });
+
+    let csv = Path::new(MATRIX_DIR).join("matrix.csv");
+    write_csv(&runs, &csv);
+    println!("\nmerged results written to {}", csv.display());
+}
@@ -0,0 +1,138 @@
+//! Locates the NTT functions in built ELF files, writes their normalized
+//! listings and prints a side by side comparison, replacing the manual
+//! `cargo asm`/`objdump --section=...` steps of the README.
+//!
+//! `ntt-diff [--out DIR] [--symbol NAME]... ELF...`
+//!
+//! Without `--symbol` the Rust and C NTTs and inverse NTTs are looked for; a
+//! `*` in NAME matches any run of characters, e.g. `pqcrystals_kyber*_ref_ntt`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use kyber_tools::elf::{self, Symbol};
+use kyber_tools::listing::{self, Class, Summary};
+
+const DEFAULT_SYMBOLS: [&str; 6] = [
+    "pqc_kyber::reference::ntt::ntt",
+    "pqc_kyber::reference::ntt::invntt",
+    "pqcrystals_kyber*_ref_ntt",
+    "pqcrystals_kyber*_ref_invntt",
+    "kyber_rust::ntt_asm::ntt_from_asm",
+    "kyber_rust::ntt_m4::ntt",
+];
+
+const DEFAULT_OUT: &str = "target/ntt-diff";
+
+const CLASSES: [Class; 8] = [
+    Class::Load,
+    Class::Store,
+    Class::Branch,
+    Class::Multiply,
+    Class::Simd,
+    Class::Compare,
+    Class::Move,
+    Class::Alu,
+];
+
+fn usage() -> ! {
+    eprintln!("usage: ntt-diff [--out DIR] [--symbol NAME]... ELF...");
+    process::exit(2)
+}
+
+struct Found {
+    label: String,
+    summary: Summary,
+}
+
+fn analyse(elf: &Path, symbol: &Symbol, out: &Path) -> std::io::Result<Found> {
+    let raw = elf::disassemble(elf, symbol, &[])?;
+    let instructions = listing::parse(&raw);
+    let stem = elf.file_stem().unwrap_or_default().to_string_lossy();
+    let file = format!("{}.{}.S", stem, symbol.short_name().replace("::", "_"));
+    fs::write(out.join(&file), listing::normalize(symbol, &instructions))?;
+    Ok(Found {
+        label: format!("{}:{}", stem, symbol.short_name().rsplit("::").next().unwrap_or("")),
+        summary: Summary::new(symbol, &instructions),
+    })
+}
+
+fn main() {
+    let mut out = PathBuf::from(DEFAULT_OUT);
+    let mut patterns = Vec::new();
+    let mut elfs = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = args.next().unwrap_or_else(|| usage()).into(),
+            "--symbol" => patterns.push(args.next().unwrap_or_else(|| usage())),
+            "-h" | "--help" => usage(),
+            _ => elfs.push(PathBuf::from(arg)),
+        }
+    }
+    if elfs.is_empty() {
+        usage();
+    }
+    if patterns.is_empty() {
+        patterns = DEFAULT_SYMBOLS.iter().map(|p| p.to_string()).collect();
+    }
+    if let Err(e) = fs::create_dir_all(&out) {
+        eprintln!("cannot create {}: {}", out.display(), e);
+        process::exit(1);
+    }
+
+    let mut found = Vec::new();
+    for elf in &elfs {
+        let symbols = match elf::symbols(elf) {
+            Ok(symbols) => symbols,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        };
+        for pattern in &patterns {
+            let hits: Vec<&Symbol> = symbols.iter().filter(|s| s.is_code() && s.size > 0 && elf::glob(pattern, s.short_name())).collect();
+            if hits.is_empty() {
+                eprintln!("{}: no `{}` (inlined, or not linked in)", elf.display(), pattern);
+            }
+            for symbol in hits {
+                match analyse(elf, symbol, &out) {
+                    Ok(f) => found.push(f),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+    if found.is_empty() {
+        process::exit(1);
+    }
+
+    println!("listings written to {}", out.display());
+    print!("{:<24}", "");
+    for f in &found {
+        print!(" {:>28}", f.label);
+    }
+    println!();
+    let row = |name: &str, value: &dyn Fn(&Summary) -> String| {
+        print!("{:<24}", name);
+        for f in &found {
+            print!(" {:>28}", value(&f.summary));
+        }
+        println!();
+    };
+    row("bytes", &|s| s.bytes.to_string());
+    row("instructions", &|s| s.instructions.to_string());
+    row("loads / stores", &|s| format!("{} / {}", s.count(Class::Load), s.count(Class::Store)));
+    row("branches", &|s| s.count(Class::Branch).to_string());
+    row("calls", &|s| s.calls.to_string());
+    row("panic_bounds_check calls", &|s| s.bounds_checks.len().to_string());
+    row("stack frame bytes", &|s| s.frame.to_string());
+    for class in CLASSES.iter() {
+        row(&format!("  {}", class.name()), &|s| s.count(*class).to_string());
+    }
+}
@@ -0,0 +1,185 @@
+//! Attributes the flash and RAM of a firmware ELF to the pqc_kyber modules,
+//! the harness and the other crates through its symbol table, and compares
+//! the totals with the regions of the memory script.
+//!
+//! `size-report [--memory memory.x] [--top N] ELF`
+//!
+//! Code inlined into a caller counts towards the caller: with LTO most of
+//! `poly` and `polyvec` ends up in `indcpa`. Bytes of a section not covered
+//! by a sized symbol (literal pools, anonymous constants) are reported as
+//! `(no symbol)`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use kyber_tools::elf::{self, Symbol};
+use kyber_tools::memory::{self, Region};
+
+/// pqc_kyber modules the code is attributed to, found in the symbol path.
+const KYBER_MODULES: [&str; 13] = [
+    "ntt",
+    "poly",
+    "polyvec",
+    "indcpa",
+    "fips202",
+    "verify",
+    "cbd",
+    "reduce",
+    "symmetric",
+    "kem",
+    "kex",
+    "api",
+    "rng",
+];
+
+/// Sections whose bytes are attributed; the others only count in the totals.
+const SECTIONS: [&str; 6] = [".text", ".rodata", ".data", ".bss", ".uninit", ".ccram"];
+
+#[derive(Default)]
+struct Usage {
+    text: u64,
+    rodata: u64,
+    /// `.data`, `.bss`, `.uninit` and `.ccram`.
+    ram: u64,
+}
+
+impl Usage {
+    fn flash(&self) -> u64 {
+        self.text + self.rodata
+    }
+
+    fn add(&mut self, section: &str, bytes: u64) {
+        match section {
+            ".text" => self.text += bytes,
+            ".rodata" => self.rodata += bytes,
+            _ => self.ram += bytes,
+        }
+    }
+}
+
+/// Group of a symbol: `pqc_kyber::<module>`, `kyber_rust::<module>`, `C reference`
+/// or the crate for other Rust paths.
+fn group(symbol: &Symbol) -> String {
+    // llvm-nm leaves some legacy escapes, e.g. `_$LT$T$u20$as$u20$Trait$GT$`
+    let name = symbol.short_name().replace("$LT$", "<").replace("$GT$", ">").replace("$u20$", " ").replace("..", "::");
+    let name = name.trim_start_matches(['_', '<', '&']);
+    if name.starts_with("pqcrystals_kyber") {
+        return "C reference".into();
+    }
+    let path: Vec<&str> = name.split([' ', '<']).next().unwrap_or("").split("::").collect();
+    match path.as_slice() {
+        ["pqc_kyber", modules @ .., _] => match modules.iter().rev().find(|m| KYBER_MODULES.contains(m)) {
+            Some(module) => format!("pqc_kyber::{}", module),
+            None => "pqc_kyber".into(),
+        },
+        ["kyber_rust", module, _, ..] => format!("kyber_rust::{}", module),
+        [krate, _, ..] => krate.to_string(),
+        _ => "(other symbols)".into(),
+    }
+}
+
+fn percent(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: size-report [--memory memory.x] [--top N] ELF");
+    process::exit(2)
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn print_region(label: &str, used: u64, region: Option<&Region>) {
+    match region {
+        Some(r) => println!("{:<6} {:>8} of {:>8} bytes ({:.1}%)", label, used, r.length, percent(used, r.length)),
+        None => println!("{:<6} {:>8} bytes", label, used),
+    }
+}
+
+fn main() {
+    let mut memory_x = PathBuf::from("memory.x");
+    let mut top = usize::MAX;
+    let mut elf = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--memory" => memory_x = args.next().unwrap_or_else(|| usage()).into(),
+            "--top" => top = args.next().and_then(|n| n.parse().ok()).unwrap_or_else(|| usage()),
+            "-h" | "--help" => usage(),
+            _ if elf.is_none() => elf = Some(PathBuf::from(arg)),
+            _ => usage(),
+        }
+    }
+    let elf = elf.unwrap_or_else(|| usage());
+    let elf = elf.as_path();
+
+    let sections = elf::sections(elf).unwrap_or_else(|e| fail(e.to_string()));
+    let mut symbols = elf::symbols(elf).unwrap_or_else(|e| fail(e.to_string()));
+    symbols.retain(|s| s.size > 0);
+    let regions = memory::regions(&memory_x).unwrap_or_else(|e| fail(format!("{}: {}", memory_x.display(), e)));
+
+    let mut groups: BTreeMap<String, Usage> = BTreeMap::new();
+    let mut total = Usage::default();
+    for (name, addr, size) in sections.iter().filter(|(name, _, _)| SECTIONS.contains(&name.as_str())) {
+        let end = addr + size;
+        // aliases (`memcpy` and `compiler_builtins::mem::memcpy`) share
+        // their bytes, only the first symbol gets them
+        let mut covered = *addr;
+        let mut attributed = 0;
+        for symbol in symbols.iter().filter(|s| s.addr >= *addr && s.addr < end) {
+            let start = symbol.addr.max(covered);
+            let stop = (symbol.addr + symbol.size).min(end);
+            if stop > start {
+                groups.entry(group(symbol)).or_default().add(name, stop - start);
+                attributed += stop - start;
+                covered = stop;
+            }
+        }
+        if name == ".text" || name == ".rodata" {
+            groups.entry("(no symbol)".into()).or_default().add(name, size - attributed);
+        }
+        total.add(name, *size);
+    }
+
+    let mut ranked: Vec<(String, Usage)> = groups.into_iter().filter(|(_, u)| u.flash() + u.ram > 0).collect();
+    ranked.sort_by(|a, b| b.1.flash().cmp(&a.1.flash()).then(b.1.ram.cmp(&a.1.ram)));
+    println!("{:<28} {:>8} {:>8} {:>8} {:>7} {:>8}", "group", ".text", ".rodata", "flash", "%", "ram");
+    for (name, u) in ranked.iter().take(top) {
+        println!(
+            "{:<28} {:>8} {:>8} {:>8} {:>6.1}% {:>8}",
+            name,
+            u.text,
+            u.rodata,
+            u.flash(),
+            percent(u.flash(), total.flash()),
+            u.ram
+        );
+    }
+    println!(
+        "{:<28} {:>8} {:>8} {:>8} {:>7} {:>8}",
+        "total",
+        total.text,
+        total.rodata,
+        total.flash(),
+        "",
+        total.ram
+    );
+
+    let footprint = elf::footprint(elf).unwrap_or_else(|e| fail(e.to_string()));
+    let region = |name: &str| regions.iter().find(|r| r.name == name);
+    println!("\nagainst {} (stack not included):", Path::new(&memory_x).display());
+    print_region("FLASH", footprint.flash, region("FLASH"));
+    print_region("RAM", footprint.ram, region("RAM"));
+    if footprint.ccram > 0 || region("CCRAM").is_some() {
+        print_region("CCRAM", footprint.ccram, region("CCRAM"));
+    }
+}
@@ -0,0 +1,155 @@
+//! Symbols and disassembly of an ELF file.
+
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    /// Start address, without the Thumb bit.
+    pub addr: u64,
+    pub size: u64,
+    /// `nm` type letter: `T`/`t` for code, `D`/`d`, `B`/`b`, `R`/`r` for data.
+    pub kind: char,
+    /// Demangled name, Rust hash included.
+    pub name: String,
+}
+
+impl Symbol {
+    /// The name without the `::h0123456789abcdef` suffix of Rust symbols.
+    pub fn short_name(&self) -> &str {
+        strip_hash(&self.name)
+    }
+
+    pub fn is_code(&self) -> bool {
+        matches!(self.kind, 'T' | 't' | 'W' | 'w')
+    }
+}
+
+/// Removes the legacy-mangling hash of a demangled Rust path.
+pub fn strip_hash(name: &str) -> &str {
+    match name.rfind("::h") {
+        Some(i) if name.len() - i == 19 && name[i + 3..].bytes().all(|b| b.is_ascii_hexdigit()) => &name[..i],
+        _ => name,
+    }
+}
+
+/// `pattern` with at most one `*`, which matches any run of characters,
+/// against a hash-free symbol name.
+pub fn glob(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => name.len() >= prefix.len() + suffix.len() && name.starts_with(prefix) && name.ends_with(suffix),
+        None => name == pattern,
+    }
+}
+
+fn tool(var: &str, default: &str) -> String {
+    env::var(var).unwrap_or_else(|_| default.to_string())
+}
+
+fn run(program: &str, args: &[&str], elf: &Path) -> io::Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .arg(elf)
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("cannot run {}: {}", program, e)))?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{} failed on {}: {}",
+            program,
+            elf.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Defined symbols with a size, in address order.
+pub fn symbols(elf: &Path) -> io::Result<Vec<Symbol>> {
+    let nm = tool("NM", "llvm-nm");
+    let out = run(&nm, &["--defined-only", "--print-size", "--demangle", "--numeric-sort"], elf)?;
+    let thumb = is_arm(elf)?;
+    Ok(out
+        .lines()
+        .filter_map(|line| {
+            // address size kind name, the demangled name may contain spaces
+            let mut fields = line.splitn(4, ' ');
+            let addr = u64::from_str_radix(fields.next()?, 16).ok()?;
+            let size = u64::from_str_radix(fields.next()?, 16).ok()?;
+            let kind = fields.next()?.chars().next()?;
+            let name = fields.next()?.to_string();
+            let addr = if thumb { addr & !1 } else { addr };
+            Some(Symbol { addr, size, kind, name })
+        })
+        .collect())
+}
+
+/// Whether `elf` is an ARM file, whose code symbols carry the Thumb bit.
+pub fn is_arm(elf: &Path) -> io::Result<bool> {
+    let objdump = tool("OBJDUMP", "llvm-objdump");
+    Ok(run(&objdump, &["-f"], elf)?.to_ascii_lowercase().contains("arm"))
+}
+
+/// Size of the sections of `elf`: `(name, address, size)`.
+pub fn sections(elf: &Path) -> io::Result<Vec<(String, u64, u64)>> {
+    let objdump = tool("OBJDUMP", "llvm-objdump");
+    let out = run(&objdump, &["-h"], elf)?;
+    Ok(out
+        .lines()
+        .filter_map(|line| {
+            // Idx Name Size VMA ...
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 || fields[0].parse::<u32>().is_err() {
+                return None;
+            }
+            let size = u64::from_str_radix(fields[2], 16).ok()?;
+            let addr = u64::from_str_radix(fields[3], 16).ok()?;
+            Some((fields[1].to_string(), addr, size))
+        })
+        .collect())
+}
+
+/// Raw `objdump -d` listing of `symbol`, demangled, without encodings.
+/// `extra` is passed on, e.g. `-l` for source lines.
+pub fn disassemble(elf: &Path, symbol: &Symbol, extra: &[&str]) -> io::Result<String> {
+    let objdump = tool("OBJDUMP", "llvm-objdump");
+    let start = format!("--start-address=0x{:x}", symbol.addr);
+    let stop = format!("--stop-address=0x{:x}", symbol.addr + symbol.size);
+    let mut args = vec!["-d", "-C", "--no-show-raw-insn", start.as_str(), stop.as_str()];
+    args.extend_from_slice(extra);
+    run(&objdump, &args, elf)
+}
+
+/// Flash and RAM taken by a cortex-m-rt image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Footprint {
+    /// Vector table, code, read-only data and the initial values of `.data`
+    /// and `.ccram`.
+    pub flash: u64,
+    /// `.data`, `.bss` and `.uninit`, without the stack.
+    pub ram: u64,
+    /// `.ccram` statics (see `ccram.x`).
+    pub ccram: u64,
+}
+
+/// Footprint of `elf` from its section headers.
+pub fn footprint(elf: &Path) -> io::Result<Footprint> {
+    let mut f = Footprint::default();
+    for (name, _, size) in sections(elf)? {
+        match name.as_str() {
+            ".vector_table" | ".text" | ".rodata" | ".gnu.sgstubs" => f.flash += size,
+            ".data" => {
+                f.flash += size;
+                f.ram += size;
+            }
+            ".ccram" => {
+                f.flash += size;
+                f.ccram += size;
+            }
+            ".bss" | ".uninit" => f.ram += size,
+            _ => {}
+        }
+    }
+    Ok(f)
+}
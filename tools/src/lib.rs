@@ -0,0 +1,11 @@
+//! Helpers shared by the host tools: symbols and disassembly of an ELF file
+//! through `nm` and `objdump`, a summary of a disassembled function and the
+//! memory regions of the linker scripts.
+//!
+//! The binaries default to `llvm-nm` and `llvm-objdump`, which handle both
+//! the Thumb firmware and host builds; `NM` and `OBJDUMP` select others
+//! (e.g. `arm-none-eabi-objdump`).
+
+pub mod elf;
+pub mod listing;
+pub mod memory;
@@ -0,0 +1,382 @@
+//! Parsed disassembly of one function: normalized listing and summary.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::elf::{strip_hash, Symbol};
+
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    /// Address of the instruction.
+    pub addr: u64,
+    pub mnemonic: String,
+    pub operands: String,
+    /// Symbol `objdump` resolved the operand to, `<...>` in the listing.
+    pub target_symbol: Option<String>,
+    /// Source line (`objdump -l`) the instruction belongs to.
+    pub line: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Class {
+    Load,
+    Store,
+    Branch,
+    Multiply,
+    Simd,
+    Compare,
+    Move,
+    Alu,
+}
+
+impl Class {
+    pub fn name(self) -> &'static str {
+        match self {
+            Class::Load => "load",
+            Class::Store => "store",
+            Class::Branch => "branch",
+            Class::Multiply => "multiply",
+            Class::Simd => "simd",
+            Class::Compare => "compare",
+            Class::Move => "move",
+            Class::Alu => "alu",
+        }
+    }
+}
+
+const CONDITIONS: [&str; 15] = [
+    "eq", "ne", "cs", "cc", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt",
+];
+
+impl Instruction {
+    /// Mnemonic without the Thumb width qualifier (`.w`, `.n`).
+    pub fn base(&self) -> &str {
+        self.mnemonic
+            .strip_suffix(".w")
+            .or_else(|| self.mnemonic.strip_suffix(".n"))
+            .unwrap_or(&self.mnemonic)
+    }
+
+    pub fn is_branch(&self) -> bool {
+        let m = self.base();
+        let conditional = m.len() == 3 && m.starts_with('b') && CONDITIONS.contains(&&m[1..]) || m == "ble";
+        matches!(m, "b" | "bl" | "blx" | "bx" | "bal" | "cbz" | "cbnz" | "tbb" | "tbh" | "call" | "callq" | "ret" | "retq" | "jmp" | "jmpq")
+            || conditional
+            || (m.starts_with('j') && m.len() <= 4)
+            || (m == "pop" && self.operands.contains("pc"))
+            || (m.starts_with("ldr") && self.operands.starts_with("pc,"))
+    }
+
+    /// Calls, as opposed to jumps.
+    pub fn is_call(&self) -> bool {
+        matches!(self.base(), "bl" | "blx" | "call" | "callq") && !self.operands.starts_with("lr")
+    }
+
+    pub fn class(&self) -> Class {
+        let m = self.base();
+        if self.is_branch() {
+            Class::Branch
+        } else if m.starts_with("ldr") || m.starts_with("ldm") || m.starts_with("vldr") || m.starts_with("vldm") || m == "pop" || m == "vpop" {
+            Class::Load
+        } else if m.starts_with("str") || m.starts_with("stm") || m.starts_with("vstr") || m.starts_with("vstm") || m == "push" || m == "vpush" {
+            Class::Store
+        } else if m.contains("mul")
+            || ["mla", "mls", "smla", "smls", "smmla", "smmls", "umla", "umaal", "smuad", "smusd", "smlad", "smlsd"]
+                .iter()
+                .any(|p| m.starts_with(p))
+        {
+            Class::Multiply
+        } else if m.starts_with("pkh") || ((m.ends_with("16") || m.ends_with("8")) && !m.starts_with("mov")) {
+            Class::Simd
+        } else if matches!(m, "cmp" | "cmn" | "tst" | "teq" | "test") || m.starts_with("it") {
+            Class::Compare
+        } else if m.starts_with("mov") || m == "mvn" || m == "mvns" || m == "lea" {
+            Class::Move
+        } else {
+            Class::Alu
+        }
+    }
+
+    /// Branch destination inside the listing, if the operand is an address.
+    pub fn branch_address(&self) -> Option<u64> {
+        if !self.is_branch() {
+            return None;
+        }
+        let operand = self.operands.rsplit([' ', ',']).next()?;
+        let operand = operand.trim_start_matches('#');
+        let operand = operand.strip_prefix("0x").unwrap_or(operand);
+        u64::from_str_radix(operand, 16).ok()
+    }
+}
+
+/// Parses the `objdump -d` output of one function.
+pub fn parse(raw: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    let mut line = None;
+    for text in raw.lines() {
+        let trimmed = text.trim_start();
+        if let Some(source) = source_line(text) {
+            line = Some(source.to_string());
+            continue;
+        }
+        let (addr, rest) = match trimmed.split_once(':') {
+            Some(split) => split,
+            None => continue,
+        };
+        let addr = match u64::from_str_radix(addr, 16) {
+            Ok(addr) if rest.starts_with(|c: char| c.is_whitespace()) => addr,
+            _ => continue,
+        };
+        let mut rest = rest.trim();
+        if rest.is_empty() {
+            continue;
+        }
+        // `<symbol+0x10>` annotation, then comments
+        let mut target_symbol = None;
+        if let (Some(open), Some(close)) = (rest.find('<'), rest.rfind('>')) {
+            if open < close {
+                let symbol = &rest[open + 1..close];
+                let symbol = symbol.split('+').next().unwrap_or(symbol);
+                target_symbol = Some(strip_hash(symbol).to_string());
+                rest = rest[..open].trim_end();
+            }
+        }
+        for comment in [" @ ", "\t@ ", " // ", "\t// ", " ; ", "\t; "].iter() {
+            if let Some(i) = rest.find(comment) {
+                rest = rest[..i].trim_end();
+            }
+        }
+        let mut parts = rest.splitn(2, char::is_whitespace);
+        let mnemonic = parts.next().unwrap_or("").to_string();
+        let operands = parts.next().unwrap_or("").trim().to_string();
+        instructions.push(Instruction { addr, mnemonic, operands, target_symbol, line: line.clone() });
+    }
+    instructions
+}
+
+/// `objdump -l` source locations: `; /path/file.rs:12` (llvm-objdump) or
+/// `/path/file.rs:12` at the start of the line (GNU objdump).
+fn source_line(text: &str) -> Option<&str> {
+    let text = text.strip_prefix("; ").unwrap_or(text);
+    if text.starts_with(char::is_whitespace) || text.ends_with(">:") {
+        return None;
+    }
+    let (file, line) = text.trim_end().rsplit_once(':')?;
+    let line = line.split_whitespace().next()?;
+    if file.is_empty() || line.parse::<u32>().is_err() {
+        return None;
+    }
+    Some(text.trim_end())
+}
+
+/// The listing without addresses: branch targets inside the function become
+/// `.L<offset>` labels, calls show the (hash-free) callee name.
+pub fn normalize(symbol: &Symbol, instructions: &[Instruction]) -> String {
+    let inside = |a: u64| a >= symbol.addr && a < symbol.addr + symbol.size;
+    let labels: Vec<u64> = instructions.iter().filter_map(|i| i.branch_address()).filter(|a| inside(*a)).collect();
+
+    let mut out = String::new();
+    writeln!(out, "; {} ({} bytes)", symbol.short_name(), symbol.size).unwrap();
+    for i in instructions {
+        if labels.contains(&i.addr) {
+            writeln!(out, ".L{:04x}:", i.addr - symbol.addr).unwrap();
+        }
+        let operands = match (i.branch_address(), &i.target_symbol) {
+            (Some(a), _) if inside(a) => {
+                // keep the registers of cbz/cbnz, replace the address
+                let prefix = i.operands.rsplit_once(' ').map(|(p, _)| p).unwrap_or("");
+                format!("{}.L{:04x}", if prefix.is_empty() { String::new() } else { format!("{} ", prefix) }, a - symbol.addr)
+            }
+            (Some(_), Some(callee)) => callee.clone(),
+            _ => i.operands.clone(),
+        };
+        writeln!(out, "    {:<8} {}", i.mnemonic, operands).unwrap();
+    }
+    out
+}
+
+/// Structured view of one function.
+#[derive(Clone, Debug, Default)]
+pub struct Summary {
+    pub bytes: u64,
+    pub instructions: usize,
+    pub classes: BTreeMap<Class, usize>,
+    pub calls: usize,
+    /// Addresses of the calls to `panic_bounds_check`.
+    pub bounds_checks: Vec<u64>,
+    /// Bytes pushed plus the explicit `sub sp` of the prologue.
+    pub frame: u64,
+}
+
+impl Summary {
+    pub fn new(symbol: &Symbol, instructions: &[Instruction]) -> Summary {
+        let mut s = Summary { bytes: symbol.size, instructions: instructions.len(), ..Summary::default() };
+        for i in instructions {
+            *s.classes.entry(i.class()).or_insert(0) += 1;
+            if i.is_call() {
+                s.calls += 1;
+                if i.target_symbol.as_deref().is_some_and(|t| t.contains("panic_bounds_check")) {
+                    s.bounds_checks.push(i.addr);
+                }
+            }
+        }
+        s.frame = frame_size(instructions);
+        s
+    }
+
+    pub fn count(&self, class: Class) -> usize {
+        self.classes.get(&class).copied().unwrap_or(0)
+    }
+}
+
+/// Stack frame set up by the prologue: `push`/`vpush`/`stmdb sp!` register
+/// lists and `sub sp` immediates, before the first branch.
+fn frame_size(instructions: &[Instruction]) -> u64 {
+    let mut frame = 0;
+    for i in instructions {
+        if i.is_branch() {
+            break;
+        }
+        let m = i.base();
+        let ops = i.operands.as_str();
+        if m == "push" || m == "vpush" || (m.starts_with("stmdb") && ops.starts_with("sp!")) {
+            let width = if m == "vpush" && ops.contains('d') { 8 } else { 4 };
+            frame += width * register_count(ops);
+        } else if matches!(m, "sub" | "subw" | "subs") {
+            // Thumb `sub sp, #16` / `sub sp, sp, #16`, x86 `sub $0x28,%rsp`, `sub rsp, 0x28`
+            let ops: Vec<&str> = ops.split(',').map(str::trim).collect();
+            if ops.first() == Some(&"sp") {
+                frame += immediate(ops.last().unwrap_or(&"")).unwrap_or(0);
+            } else if ops.last().is_some_and(|o| *o == "%rsp" || *o == "%esp") {
+                frame += immediate(ops[0]).unwrap_or(0);
+            } else if ops.first().is_some_and(|o| *o == "rsp" || *o == "esp") && ops.len() == 2 {
+                frame += immediate(ops[1]).unwrap_or(0);
+            }
+        }
+    }
+    frame
+}
+
+fn immediate(op: &str) -> Option<u64> {
+    let op = op.trim_start_matches(['#', '$']);
+    match op.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => op.parse().ok(),
+    }
+}
+
+/// Registers in an ARM register list, `{r4, r5, r6, r7, lr}` or `{r4-r7, lr}`.
+fn register_count(list: &str) -> u64 {
+    let list = list.trim_start_matches("sp!,").trim().trim_start_matches('{').trim_end_matches('}');
+    list.split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|r| match r.split_once('-') {
+            Some((a, b)) => {
+                let n = |r: &str| r.trim_start_matches(|c: char| c.is_ascii_alphabetic()).parse::<u64>().unwrap_or(0);
+                n(b).saturating_sub(n(a)) + 1
+            }
+            None => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `llvm-objdump -d -C --no-show-raw-insn` of a bounds-checked loop, with
+    /// two `-l` source lines added.
+    const EXCERPT: &str = "\
+00010000 <poly_ntt>:
+; /src/ntt.rs:10
+   10000:      \tpush\t{r4, r5, r6, r7, lr}
+   10002:      \tsub\tsp, #16
+   10004:      \tmovs\tr2, #0
+; /src/ntt.rs:12
+   10006:      \tcmp\tr2, #255
+   10008:      \tbhi\t0x10020 <poly_ntt+0x20> @ imm = #20
+   1000a:      \tldrsh.w\tr3, [r0, r2, lsl #1]
+   1000e:      \tmul\tr3, r3, r1
+   10012:      \tstrh.w\tr3, [r0, r2, lsl #1]
+   10016:      \tadds\tr2, #1
+   10018:      \tcmp\tr2, #128
+   1001a:      \tbne\t0x10006 <poly_ntt+0x6>  @ imm = #-24
+   1001c:      \tadd\tsp, #16
+   1001e:      \tpop\t{r4, r5, r6, r7, pc}
+   10020:      \tbl\t0x10024 <core_panicking_panic_bounds_check> @ imm = #0
+";
+
+    fn symbol() -> Symbol {
+        Symbol { addr: 0x10000, size: 0x24, kind: 'T', name: "poly_ntt".to_string() }
+    }
+
+    #[test]
+    fn parses_instructions() {
+        let instructions = parse(EXCERPT);
+        assert_eq!(instructions.len(), 14);
+
+        let bhi = &instructions[4];
+        assert_eq!((bhi.addr, bhi.mnemonic.as_str(), bhi.operands.as_str()), (0x10008, "bhi", "0x10020"));
+        assert_eq!(bhi.target_symbol.as_deref(), Some("poly_ntt"));
+        assert_eq!(bhi.branch_address(), Some(0x10020));
+        assert_eq!(bhi.line.as_deref(), Some("/src/ntt.rs:12"));
+
+        let ldrsh = &instructions[5];
+        assert_eq!((ldrsh.base(), ldrsh.operands.as_str()), ("ldrsh", "r3, [r0, r2, lsl #1]"));
+        assert_eq!(instructions[0].line.as_deref(), Some("/src/ntt.rs:10"));
+        assert!(instructions[13].is_call());
+    }
+
+    #[test]
+    fn summary() {
+        let s = Summary::new(&symbol(), &parse(EXCERPT));
+        assert_eq!((s.bytes, s.instructions, s.calls), (36, 14, 1));
+        assert_eq!(s.bounds_checks, vec![0x10020]);
+        // push {r4-r7, lr} and sub sp, #16
+        assert_eq!(s.frame, 5 * 4 + 16);
+        let counts: Vec<(Class, usize)> = s.classes.into_iter().collect();
+        assert_eq!(
+            counts,
+            vec![
+                (Class::Load, 1),
+                (Class::Store, 2),
+                (Class::Branch, 4),
+                (Class::Multiply, 1),
+                (Class::Compare, 2),
+                (Class::Move, 1),
+                (Class::Alu, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn normalized_listing() {
+        let expected = "\
+; poly_ntt (36 bytes)
+    push     {r4, r5, r6, r7, lr}
+    sub      sp, #16
+    movs     r2, #0
+.L0006:
+    cmp      r2, #255
+    bhi      .L0020
+    ldrsh.w  r3, [r0, r2, lsl #1]
+    mul      r3, r3, r1
+    strh.w   r3, [r0, r2, lsl #1]
+    adds     r2, #1
+    cmp      r2, #128
+    bne      .L0006
+    add      sp, #16
+    pop      {r4, r5, r6, r7, pc}
+.L0020:
+    bl       core_panicking_panic_bounds_check
+";
+        assert_eq!(normalize(&symbol(), &parse(EXCERPT)), expected);
+    }
+
+    #[test]
+    fn register_lists() {
+        assert_eq!(register_count("{r4-r7, lr}"), 5);
+        assert_eq!(register_count("sp!, {r4, r5, r6, r7, r8, r9, r10, r11, lr}"), 9);
+    }
+}
@@ -0,0 +1,127 @@
+//! Regions of a linker `MEMORY` block, e.g. `memory.x`.
+
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub name: String,
+    pub origin: u64,
+    pub length: u64,
+}
+
+/// Regions of the `MEMORY` block of `path`.
+pub fn regions(path: &Path) -> io::Result<Vec<Region>> {
+    let script = fs::read_to_string(path)?;
+    let invalid = |line: &str| io::Error::new(io::ErrorKind::InvalidData, format!("{}: cannot parse `{}`", path.display(), line.trim()));
+    let block = match script.find("MEMORY") {
+        Some(start) => &script[start..],
+        None => return Ok(Vec::new()),
+    };
+    let block = &block[..block.find('}').unwrap_or(block.len())];
+    let mut regions = Vec::new();
+    for line in strip_comments(block).lines() {
+        // NAME (attributes) : ORIGIN = expr, LENGTH = expr
+        let (name, rest) = match line.split_once(':') {
+            Some(split) => split,
+            None => continue,
+        };
+        let name = name.split('(').next().unwrap_or("").trim().to_string();
+        let (origin, length) = rest.split_once(',').ok_or_else(|| invalid(line))?;
+        let value = |field: &str| field.split_once('=').and_then(|(_, expr)| eval(expr)).ok_or_else(|| invalid(line));
+        regions.push(Region {
+            name,
+            origin: value(origin)?,
+            length: value(length)?,
+        });
+    }
+    Ok(regions)
+}
+
+fn strip_comments(script: &str) -> String {
+    let mut out = String::new();
+    let mut rest = script;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = rest[start..].find("*/").map_or("", |end| &rest[start + end + 2..]);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Sum and difference of numbers with an optional `K`/`M` suffix, which is
+/// all the memory scripts of this repository use.
+fn eval(expr: &str) -> Option<u64> {
+    let mut total: i64 = 0;
+    let mut sign = 1;
+    for token in expr.replace('+', " + ").replace('-', " - ").split_whitespace() {
+        match token {
+            "+" => sign = 1,
+            "-" => sign = -1,
+            _ => total += sign * number(token)? as i64,
+        }
+    }
+    u64::try_from(total).ok()
+}
+
+fn number(token: &str) -> Option<u64> {
+    let (digits, scale) = match token.as_bytes().last()? {
+        b'K' | b'k' => (&token[..token.len() - 1], 1 << 10),
+        b'M' | b'm' => (&token[..token.len() - 1], 1 << 20),
+        _ => (token, 1),
+    };
+    let value = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None => digits.parse().ok()?,
+    };
+    Some(value * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn script(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(name)
+    }
+
+    fn region(name: &str, origin: u64, length: u64) -> Region {
+        Region { name: name.to_string(), origin, length }
+    }
+
+    #[test]
+    fn board_memory() {
+        let expected = vec![
+            region("FLASH", 0x0800_0000, 1 << 20),
+            region("RAM", 0x2000_0000, 128 << 10),
+            region("CCRAM", 0x1000_0000, 64 << 10),
+        ];
+        assert_eq!(regions(&script("memory.x")).unwrap(), expected);
+    }
+
+    #[test]
+    fn qemu_memory_with_sums_and_differences() {
+        let expected = vec![
+            region("FLASH", 0, 4 << 20),
+            region("RAM", 0x2000_0000, (4 << 20) - (64 << 10)),
+            region("CCRAM", 0x203f_0000, 64 << 10),
+        ];
+        assert_eq!(regions(&script("memory-qemu.x")).unwrap(), expected);
+    }
+
+    #[test]
+    fn script_without_memory_block() {
+        assert_eq!(regions(&script("ccram.x")).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn expressions() {
+        assert_eq!(eval(" 0x20000000 + 4M - 64K"), Some(0x203f_0000));
+        assert_eq!(eval("0X10k"), Some(16 << 10));
+        assert_eq!(eval("1 - 2"), None);
+        assert_eq!(eval("ORIGIN(RAM)"), None);
+    }
+}
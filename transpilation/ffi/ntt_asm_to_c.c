@@ -0,0 +1,54 @@
+/*
+ * The "ALTERNATIVE" translation of transpilation/ntt_asm_to_c.c, made
+ * callable for the differential tests. Only the lines marked `ffi:` differ
+ * from the translation:
+ *  - the function is renamed so it does not clash with the C reference;
+ *  - the zetas left as `{ precomputed zetas }` are the 128 pqc_kyber zetas,
+ *    zero padded because the translation indexes the table up to 191.
+ * The translation also writes up to `r[382]`, the caller pads `r`.
+ *
+ * The first translation in ntt_asm_to_c.c is not built: it calls an
+ * undefined `montgomery_reduce(int16_t *)`, uses `pqcrystals_kyber1024_ref_zetas`
+ * without declaring it and its `while (1)` only ends on data dependent
+ * conditions.
+ */
+
+#include <stdint.h>
+#include <stddef.h>
+
+/* ffi: was `pqcrystals_kyber1024_ref_ntt` */
+void ntt_asm_to_c(uint16_t *r) {
+    size_t i, j, k;
+    uint16_t t, zeta;
+    /* ffi: was `const uint16_t zetas[128] = { precomputed zetas };` */
+    const uint16_t zetas[256] = {
+        -1044,  -758,  -359, -1517,  1493,  1422,   287,   202,
+         -171,   622,  1577,   182,   962, -1202, -1474,  1468,
+          573, -1325,   264,   383,  -829,  1458, -1602,  -130,
+         -681,  1017,   732,   608, -1542,   411,  -205, -1571,
+         1223,   652,  -552,  1015, -1293,  1491,  -282, -1544,
+          516,    -8,  -320,  -666, -1618, -1162,   126,  1469,
+         -853,   -90,  -271,   830,   107, -1421,  -247,  -951,
+         -398,   961, -1508,  -725,   448, -1065,   677, -1275,
+        -1103,   430,   555,   843, -1251,   871,  1550,   105,
+          422,   587,   177,  -235,  -291,  -460,  1574,  1653,
+         -246,   778,  1159,  -147,  -777,  1483,  -602,  1119,
+        -1590,   644,  -872,   349,   418,   329,  -156,   -75,
+          817,  1097,   603,   610,  1322, -1285, -1465,   384,
+        -1215,  -136,  1218, -1335,  -874,   220, -1187, -1659,
+        -1185, -1530, -1278,   794, -1510,  -854,  -870,   478,
+         -108,  -308,   996,   991,   958, -1460,  1522,  1628,
+    };
+    const uint16_t q = 3329; // Example modulus, replace with actual value if different
+
+    for (i = 1; i < 128; i <<= 1) {
+        for (j = 0; j < 256; j = k) {
+            zeta = zetas[i + (j >> 1)];
+            for (k = j; k < j + i; ++k) {
+                t = r[k + i] * zeta;
+                r[k + i] = r[k] + q - t;
+                r[k] = r[k] + t;
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * First translation of transpilation/rust_ntt_to_c.c (the `__cortex_m_rt_main`
+ * up to the first closing brace), made callable for the differential tests.
+ * Only the lines marked `ffi:` differ from the translation:
+ *  - `<cstdint>` is C++ and is dropped;
+ *  - the 512 halfword `buffer` and the `coefficients` table, which the
+ *    translation reads from 0x08000000 instead of the zetas symbol, are
+ *    parameters. The caller pads `buffer`, the last loop reads `ptr[-4]`.
+ *
+ * The rest of rust_ntt_to_c.c is not built: even with `<cstdint>` shimmed,
+ * its later translations use undeclared registers (`r0`, `r1`, `r0_ptr`, ...)
+ * and define `__cortex_m_rt_main` a second time.
+ */
+
+#include <string.h>
+#include <stdint.h>
+#include <inttypes.h>
+
+/* ffi: was `void __cortex_m_rt_main()` with a local zeroed buffer */
+void rust_ntt_to_c(uint16_t *buffer, const int16_t *coefficients) {
+    for (int i = 0; i < 128; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 0; j < 256; j += 128) {
+            int k = j + 128 > 256 ? 256 - j : 128;
+
+            for (int l = j; l < j + k; l++) {
+                int32_t acc = ((int32_t) buffer[l + 256] * coeff) >> 16;
+                int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+                buffer[l + 256] = buffer[l] - (tmp >> 16);
+                buffer[l] = buffer[l] + (tmp >> 16);
+            }
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 128; j < 256; j += 64) {
+            int k = j + 64 > 256 ? 256 - j : 64;
+
+            for (int l = j; l < j + k; l++) {
+                int32_t acc = ((int32_t) buffer[l] * coeff) >> 16;
+                int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+                buffer[l - 128] = buffer[l - 128] - (tmp >> 16);
+                buffer[l - 128] = buffer[l - 128] + (tmp >> 16);
+            }
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 64; j < 256; j += 32) {
+            int k = j + 32 > 256 ? 256 - j : 32;
+
+            for (int l = j; l < j + k; l++) {
+                int32_t acc = ((int32_t) buffer[l] * coeff) >> 16;
+                int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+                buffer[l - 64] = buffer[l - 64] - (tmp >> 16);
+                buffer[l - 64] = buffer[l - 64] + (tmp >> 16);
+            }
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 32; j < 256; j += 16) {
+            int k = j + 16 > 256 ? 256 - j : 16;
+
+            for (int l = j; l < j + k; l++) {
+                int32_t acc = ((int32_t) buffer[l] * coeff) >> 16;
+                int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+                buffer[l - 32] = buffer[l - 32] - (tmp >> 16);
+                buffer[l - 32] = buffer[l - 32] + (tmp >> 16);
+            }
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 0; j < 248; j += 8) {
+            int16_t* ptr = &buffer[j];
+
+            for (int l = 0; l < 8; l++) {
+                int32_t acc = ((int32_t) ptr[l + 16] * coeff) >> 16;
+                int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+                ptr[l + 16] = ptr[l] - (tmp >> 16);
+                ptr[l] = ptr[l] + (tmp >> 16);
+            }
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+
+        for (int j = 0; j < 252; j += 4) {
+            int16_t* ptr = &buffer[j];
+
+            int32_t acc = ((int32_t) ptr[8] * coeff) >> 16;
+            int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            ptr[8] = ptr[0] - (tmp >> 16);
+            ptr[0] = ptr[0] + (tmp >> 16);
+
+            acc = ((int32_t) ptr[10] * coeff) >> 16;
+            tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            ptr[10] = ptr[2] - (tmp >> 16);
+            ptr[2] = ptr[2] + (tmp >> 16);
+
+            acc = ((int32_t) ptr[12] * coeff) >> 16;
+            tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            ptr[12] = ptr[4] - (tmp >> 16);
+            ptr[4] = ptr[4] + (tmp >> 16);
+
+            acc = ((int32_t) *(&buffer[14]) * coeff) >> 16;
+            tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            *(&buffer[14]) = ptr[6] - (tmp >> 16);
+            ptr[6] = ptr[6] + (tmp >> 16);
+        }
+    }
+
+    for (int i = 128; i < 256; i++) {
+        int16_t coeff = coefficients[i];
+        int remaining = 256 - i;
+
+        for (int j = 0; j < 254 && remaining > 0; j += 2, remaining--) {
+            int16_t* ptr = &buffer[j];
+
+            int32_t acc = ((int32_t) ptr[0] * coeff) >> 16;
+            int32_t tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            ptr[0] = ptr[-4] - (tmp >> 16);
+            ptr[-4] = ptr[-4] + (tmp >> 16);
+
+            acc = ((int32_t) ptr[2] * coeff) >> 16;
+            tmp = ((acc * 62209) >> 16) + (acc & 0xFFFF);
+            ptr[2] = ptr[-2] - (tmp >> 16);
+            ptr[-2] = ptr[-2] + (tmp >> 16);
+        }
+    }
+}